@@ -8,12 +8,12 @@
 //! into DB blobs like diesel etc. For simplicity, this example focuses on the core serialization
 //! and deserialization with strong blob typing.
 
-use std::borrow::Cow;
-use std::marker::PhantomData;
-use std::ops::Deref;
 use serde::{Deserialize, Serialize};
 use serde_poly::{DeserializePoly, DeserializePolyOwned, OwnablePoly, SerializePoly};
 use serde_poly_macro::Poly;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::ops::Deref;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // lifetimes in this example can all be elided, but are shown explicitly for clarity
@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let json: Json<'static, MyTypePoly> = Json::serialize(&data)?;
-    let json_str: &str = &json;  // deref to the underlying &str
+    let json_str: &str = &json; // deref to the underlying &str
     println!("Serialized JSON: {}", json_str);
 
     // send the string over the wire, store in DB, etc.
@@ -42,21 +42,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-#[derive(Serialize, Deserialize, Poly, OwnablePoly)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
 pub struct MyType<'a> {
     pub name: String,
     /// Nest a serialized JSON blob in this type!
+    #[serde(borrow)]
     pub data: Json<'a, Vec<u64>>,
 }
 
 /// A strongly typed JSON blob wrapper that can serialize/deserialize any type T to/from JSON.
 ///
 /// The inner type could be a `Cow<'a, str>` or `Cow<'a, [u8]>` depending on the use case.
-#[derive(Clone, Eq, PartialEq)]
-#[derive(Serialize, Deserialize, Poly, OwnablePoly)]
-#[serde(transparent)]  // ensure we serialize/deserialize as the inner string
-pub struct Json<'a, T>(Cow<'a, str>, #[serde(skip)] PhantomData<fn() -> T>);
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[serde(transparent)] // ensure we serialize/deserialize as the inner string
+pub struct Json<'a, T>(
+    #[serde(borrow)] Cow<'a, str>,
+    #[serde(skip)] PhantomData<fn() -> T>,
+);
 
 impl<T: SerializePoly> Json<'_, T> {
     pub fn serialize(item: &T) -> Result<Json<'static, T::Out>, serde_json::Error> {
@@ -73,7 +75,7 @@ impl<'a, T: DeserializePoly> Json<'a, T> {
 
     pub fn deserialize_into_owned<R>(&'a self) -> Result<R, serde_json::Error>
     where
-            for<'b> T::Out<'b>: OwnablePoly<Owned = R>,
+        for<'b> T::Out<'b>: OwnablePoly<Owned = R>,
     {
         let item: T::Out<'a> = self.deserialize()?;
         Ok(item.into_owned())
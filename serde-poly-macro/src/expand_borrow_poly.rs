@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, GenericParam, Lifetime, Path};
+
+use crate::expand_ownable_poly::{all_fields, parse_crate_path};
+use crate::expand_poly::type_mentions_any;
+use crate::lifetimes::type_contains_any_lifetime;
+
+pub fn expand_borrow_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        attrs,
+        ident,
+        mut generics,
+        data,
+        ..
+    } = input;
+
+    let krate = parse_crate_path(&attrs)?;
+
+    let lifetime_params: Vec<Lifetime> =
+        generics.lifetimes().map(|lt| lt.lifetime.clone()).collect();
+    let type_params: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => Some(ty.ident.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let body = match &data {
+        Data::Struct(data_struct) => {
+            let (pattern, values) =
+                reborrowed_fields(&krate, &data_struct.fields, &lifetime_params);
+            quote! {
+                let #ident #pattern = self;
+                #ident #values
+            }
+        }
+        Data::Enum(data_enum) => {
+            let arms = data_enum.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let (pattern, values) =
+                    reborrowed_fields(&krate, &variant.fields, &lifetime_params);
+                quote! {
+                    #ident::#variant_ident #pattern => #ident::#variant_ident #values
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "BorrowPoly derive does not support unions",
+            ));
+        }
+    };
+
+    // The borrowed type has all of the type's lifetimes set to the reborrow's.
+    let borrow_lifetime = Lifetime::new("'__borrow", proc_macro2::Span::call_site());
+    let mut borrowed_generics = generics.clone();
+    for param in &mut borrowed_generics.params {
+        if let GenericParam::Lifetime(lt) = param {
+            lt.lifetime = borrow_lifetime.clone();
+        }
+    }
+    let (_, borrowed_ty_generics, _) = borrowed_generics.split_for_impl();
+
+    // Fields without the type's lifetimes are cloned, which generic ones can't be checked for.
+    let mut cloned = Vec::new();
+    for field in all_fields(&data) {
+        if !type_contains_any_lifetime(&field.ty, &lifetime_params)
+            && type_mentions_any(&field.ty, &type_params)
+        {
+            cloned.push(field.ty.clone());
+        }
+    }
+    if !cloned.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for ty in cloned {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ty: ::core::clone::Clone));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let borrowed_ty = match lifetime_params.is_empty() {
+        true => quote!(Self),
+        false => quote!(#ident #borrowed_ty_generics),
+    };
+
+    Ok(quote! {
+        impl #impl_generics #krate::BorrowPoly for #ident #ty_generics #where_clause {
+            type Borrowed<#borrow_lifetime> = #borrowed_ty where Self: #borrow_lifetime;
+
+            #[allow(clippy::clone_on_copy)]
+            fn reborrow(&self) -> <Self as #krate::BorrowPoly>::Borrowed<'_> {
+                #body
+            }
+        }
+    })
+}
+
+/// A pattern binding the fields by reference, and the fields of the borrowed value: those
+/// borrowing the type's lifetimes are reborrowed, and the rest are cloned.
+fn reborrowed_fields(
+    krate: &Path,
+    fields: &Fields,
+    lifetime_params: &[Lifetime],
+) -> (TokenStream2, TokenStream2) {
+    let bindings: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", i),
+        })
+        .collect();
+    let values = fields.iter().zip(&bindings).map(|(field, binding)| {
+        match type_contains_any_lifetime(&field.ty, lifetime_params) {
+            true => quote!(#krate::BorrowPoly::reborrow(#binding)),
+            false => quote!(::core::clone::Clone::clone(#binding)),
+        }
+    });
+    match fields {
+        Fields::Named(_) => (
+            quote!({ #(#bindings),* }),
+            quote!({ #(#bindings: #values),* }),
+        ),
+        Fields::Unnamed(_) => (quote!((#(#bindings),*)), quote!((#(#values),*))),
+        Fields::Unit => (quote!(), quote!()),
+    }
+}
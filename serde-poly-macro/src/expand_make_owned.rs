@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, GenericParam, Lifetime, Path, Type};
+
+use crate::expand_ownable_poly::parse_crate_path;
+use crate::expand_poly::type_mentions_any;
+use crate::lifetimes::type_contains_any_lifetime;
+
+pub fn expand_make_owned(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        attrs,
+        ident,
+        mut generics,
+        data,
+        ..
+    } = input;
+
+    let krate = parse_crate_path(&attrs)?;
+
+    let lifetime_params: Vec<Lifetime> =
+        generics.lifetimes().map(|lt| lt.lifetime.clone()).collect();
+    let type_params: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => Some(ty.ident.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let body = match &data {
+        Data::Struct(data_struct) => {
+            let (pattern, calls) = owned_fields(&krate, &data_struct.fields, &lifetime_params)?;
+            quote! {
+                let Self #pattern = self;
+                #(#calls)*
+            }
+        }
+        Data::Enum(data_enum) => {
+            let mut arms = Vec::new();
+            for variant in &data_enum.variants {
+                let variant_ident = &variant.ident;
+                let (pattern, calls) = owned_fields(&krate, &variant.fields, &lifetime_params)?;
+                arms.push(quote! {
+                    Self::#variant_ident #pattern => {
+                        #(#calls)*
+                    }
+                });
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "MakeOwned derive does not support unions",
+            ));
+        }
+    };
+
+    // Generic field types can't be checked here, so they're bounded on the impl.
+    let mut bounded = Vec::new();
+    for field in crate::expand_ownable_poly::all_fields(&data) {
+        if type_contains_any_lifetime(&field.ty, &lifetime_params)
+            && type_mentions_any(&field.ty, &type_params)
+        {
+            bounded.push(field.ty.clone());
+        }
+    }
+    if !bounded.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for ty in bounded {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ty: #krate::MakeOwned));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::MakeOwned for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn make_owned(&mut self) {
+                #body
+            }
+        }
+    })
+}
+
+/// A pattern binding the fields, and the calls owning those that borrow one of the lifetimes.
+/// Fields without the lifetimes are already owned, and are left alone.
+fn owned_fields(
+    krate: &Path,
+    fields: &Fields,
+    lifetime_params: &[Lifetime],
+) -> syn::Result<(TokenStream2, Vec<TokenStream2>)> {
+    let mut bindings = Vec::new();
+    let mut calls = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let binding = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", i),
+        };
+        if type_contains_any_lifetime(&field.ty, lifetime_params) {
+            if let Type::Reference(_) = &field.ty {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "MakeOwned can't own a reference in place, as the owned data has nowhere to \
+                     live; use a Cow instead",
+                ));
+            }
+            calls.push(quote! {
+                #krate::MakeOwned::make_owned(#binding);
+            });
+        }
+        bindings.push(binding);
+    }
+    let pattern = match fields {
+        Fields::Named(_) => quote!({ #(#bindings),* }),
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        Fields::Unit => quote!(),
+    };
+    Ok((pattern, calls))
+}
@@ -1,18 +1,37 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote};
 use syn::{
-    spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, GenericParam, Ident, Lifetime,
-    PathArguments, Type, Variant,
+    Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, GenericParam, Ident, Lifetime,
+    Lit, Meta, Path, PathArguments, Token, Type, Variant, punctuated::Punctuated, spanned::Spanned,
+    visit_mut::VisitMut,
+};
+
+use crate::expand_poly::{is_phantom_data, parse_bound, type_mentions_any};
+use crate::lifetimes::{
+    MapLifetimes, bound_contains_any_lifetime, find_borrowed_reference, find_erased_borrow,
+    type_contains_any_lifetime,
 };
 
 pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
     let DeriveInput {
+        attrs,
         ident,
-        generics,
+        mut generics,
         data,
         ..
     } = input;
 
+    let krate = parse_crate_path(&attrs)?;
+
+    // `#[ownable(bound = "...")]` predicates are added to those the derive infers.
+    let ownable_attrs = parse_ownable_attrs(&attrs)?;
+    if !ownable_attrs.bound.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(ownable_attrs.bound);
+    }
+
     // Extract lifetime parameters
     let lifetime_params: Vec<_> = generics
         .params
@@ -23,20 +42,69 @@ pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
         })
         .collect();
 
+    // Collect type parameter idents
+    let type_params: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => Some(ty.ident.clone()),
+            _ => None,
+        })
+        .collect();
+    // Generic fields are converted too, unless `#[ownable(no_bound)]` leaves them to be moved.
+    let bounded_params: &[Ident] = match ownable_attrs.no_bound {
+        true => &[],
+        false => &type_params,
+    };
+
+    // `#[ownable(clone)]` fields are moved through as they are, so their type must already be
+    // `'static`: a borrow of the type's lifetimes is rejected, and a type parameter is bounded.
+    for field in all_fields(&data) {
+        let field_attrs = parse_ownable_field_attrs(&field.attrs)?;
+        if let (Some(_), Some(skip)) = (&field_attrs.clone, &field_attrs.skip) {
+            return Err(syn::Error::new(
+                skip.span,
+                "ownable(skip) replaces the field, which ownable(clone) moves through instead",
+            ));
+        }
+        let Some(span) = field_attrs.clone else {
+            continue;
+        };
+        if type_contains_any_lifetime(&field.ty, &lifetime_params) {
+            return Err(syn::Error::new(
+                span,
+                "ownable(clone) moves the field through unchanged, so its type can't borrow the \
+                 type's lifetimes",
+            ));
+        }
+        if type_mentions_any(&field.ty, &type_params) {
+            let ty = &field.ty;
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#ty: 'static));
+        }
+    }
+
     // For types without lifetimes, we implement OwnablePoly with Owned = Self
     if lifetime_params.is_empty() {
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         return Ok(quote! {
-            impl #impl_generics ::serde_poly::OwnablePoly for #ident #ty_generics #where_clause {
+            impl #impl_generics #krate::OwnablePoly for #ident #ty_generics #where_clause {
                 type Owned = Self;
 
                 fn into_owned(self) -> Self::Owned {
                     self
                 }
+
+                #krate::__impl_ownable_identity!();
             }
         });
     }
 
+    lifetime_bound_check(&generics, &lifetime_params)?;
+    erased_borrow_check(&data, &lifetime_params)?;
+
     // Generate the Owned type with all lifetimes replaced by 'static
     let mut owned_generics = generics.clone();
     for param in &mut owned_generics.params {
@@ -48,21 +116,96 @@ pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
     // Create impl generics with 'static bounds for type parameters
     let mut impl_generics_with_static = generics.clone();
 
-    // Collect type parameter idents
-    let type_params: Vec<_> = generics
-        .params
-        .iter()
-        .filter_map(|param| match param {
-            GenericParam::Type(ty) => Some(ty.ident.clone()),
-            _ => None,
-        })
-        .collect();
-
     // Add 'static bounds to where clause for all type parameters
     if !type_params.is_empty() {
         let where_clause = impl_generics_with_static.make_where_clause();
-        for type_param in type_params {
-            where_clause.predicates.push(syn::parse_quote!(#type_param: 'static));
+        for type_param in &type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#type_param: 'static));
+        }
+    }
+
+    // A generic field's conversion has no impl to check, so bound its type to convert to its
+    // `'static` form, as the owned type's field has. With `#[ownable(no_bound)]`, only projections
+    // such as `P::Out<'a>` within borrowed fields are bounded. The bounds are higher-ranked over
+    // the type's lifetimes, as the owned type's own impl needs them for `'static`.
+    let mut bounded = Vec::new();
+    for field in all_fields(&data) {
+        let field_attrs = parse_ownable_field_attrs(&field.attrs)?;
+        if field_attrs.skip.is_some() || field_attrs.clone.is_some() {
+            continue;
+        }
+        if is_generic_data(&field.ty, bounded_params) {
+            bounded.push(&field.ty);
+        } else if type_contains_any_lifetime(&field.ty, &lifetime_params) {
+            let mut projections = Vec::new();
+            collect_projections(&field.ty, &type_params, &mut projections);
+            bounded.extend(
+                projections
+                    .into_iter()
+                    .filter(|ty| type_contains_any_lifetime(ty, &lifetime_params)),
+            );
+        }
+    }
+    let mut seen = Vec::new();
+    for ty in bounded {
+        let key = ty.to_token_stream().to_string();
+        if seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        let mut binders: Vec<Lifetime> = Vec::new();
+        let mut bound = ty.clone();
+        MapLifetimes::new(|lifetime: &Lifetime| {
+            lifetime_params.contains(lifetime).then(|| {
+                let binder = Lifetime::new(
+                    &format!("'__{}", lifetime.ident.to_string().trim_start_matches("r#")),
+                    lifetime.span(),
+                );
+                if !binders.contains(&binder) {
+                    binders.push(binder.clone());
+                }
+                binder
+            })
+        })
+        .visit_type_mut(&mut bound);
+        let mut owned = ty.clone();
+        MapLifetimes::new(|lifetime: &Lifetime| {
+            lifetime_params
+                .contains(lifetime)
+                .then(|| Lifetime::new("'static", lifetime.span()))
+        })
+        .visit_type_mut(&mut owned);
+        let predicate: syn::WherePredicate = match binders.is_empty() {
+            true => syn::parse_quote!(#bound: #krate::OwnablePoly<Owned = #owned>),
+            false => syn::parse_quote!(
+                for<#(#binders),*> #bound: #krate::OwnablePoly<Owned = #owned>
+            ),
+        };
+        impl_generics_with_static
+            .make_where_clause()
+            .predicates
+            .push(predicate);
+    }
+
+    // A skipped generic field is replaced with its owned type's default, which has no impl to check.
+    for field in all_fields(&data) {
+        let Some(skip) = parse_ownable_field_attrs(&field.attrs)?.skip else {
+            continue;
+        };
+        if skip.default.is_none() && type_mentions_any(&field.ty, &type_params) {
+            let mut owned = field.ty.clone();
+            MapLifetimes::new(|lifetime: &Lifetime| {
+                lifetime_params
+                    .contains(lifetime)
+                    .then(|| Lifetime::new("'static", lifetime.span()))
+            })
+            .visit_type_mut(&mut owned);
+            impl_generics_with_static
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#owned: ::core::default::Default));
         }
     }
 
@@ -72,14 +215,23 @@ pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
     // Generate transformation body based on data type
     let transformation_body = match data {
         Data::Struct(data_struct) => {
-            let field_transformations = generate_field_transformations(&data_struct.fields, &lifetime_params)?;
+            let field_transformations = generate_field_transformations(
+                &krate,
+                &data_struct.fields,
+                &lifetime_params,
+                bounded_params,
+            )?;
             quote! {
                 #ident #field_transformations
             }
         }
-        Data::Enum(data_enum) => {
-            generate_enum_transformation(&ident, &data_enum.variants, &lifetime_params)?
-        }
+        Data::Enum(data_enum) => generate_enum_transformation(
+            &krate,
+            &ident,
+            &data_enum.variants,
+            &lifetime_params,
+            bounded_params,
+        )?,
         Data::Union(data_union) => {
             return Err(syn::Error::new(
                 data_union.union_token.span(),
@@ -89,10 +241,10 @@ pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
     };
 
     Ok(quote! {
-        impl #impl_generics ::serde_poly::OwnablePoly for #ident #ty_generics #where_clause {
+        impl #impl_generics #krate::OwnablePoly for #ident #ty_generics #where_clause {
             type Owned = #ident #owned_ty_generics;
 
-            fn into_owned(self) -> <Self as ::serde_poly::OwnablePoly>::Owned {
+            fn into_owned(self) -> <Self as #krate::OwnablePoly>::Owned {
                 #transformation_body
             }
         }
@@ -100,79 +252,81 @@ pub fn expand_ownable_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
 }
 
 fn generate_enum_transformation(
+    krate: &Path,
     enum_ident: &Ident,
     variants: &syn::punctuated::Punctuated<Variant, syn::token::Comma>,
     lifetime_params: &[Lifetime],
+    bounded_params: &[Ident],
 ) -> syn::Result<TokenStream2> {
-    let match_arms = variants.iter().map(|variant| {
+    let mut match_arms = Vec::new();
+    for variant in variants {
         let variant_ident = &variant.ident;
 
         match &variant.fields {
             Fields::Named(fields_named) => {
                 // Generate patterns and transformations for named fields
-                let field_names: Vec<_> = fields_named.named.iter()
-                    .map(|f| f.ident.as_ref().unwrap())
-                    .collect();
-
-                let field_inits = fields_named.named.iter().map(|field| {
+                let mut field_patterns = Vec::new();
+                let mut field_inits = Vec::new();
+                for field in &fields_named.named {
                     let field_name = field.ident.as_ref().unwrap();
-                    let has_lifetime = type_contains_any_lifetime(&field.ty, lifetime_params);
-
-                    if has_lifetime {
-                        quote! {
-                            #field_name: ::serde_poly::OwnablePoly::into_owned(#field_name)
-                        }
-                    } else {
-                        quote! {
-                            #field_name
-                        }
-                    }
-                });
+                    let value = owned_value(
+                        krate,
+                        field,
+                        quote!(#field_name),
+                        lifetime_params,
+                        bounded_params,
+                    )?;
+                    field_patterns.push(match is_skipped(field)? {
+                        true => quote!(#field_name: _),
+                        false => quote!(#field_name),
+                    });
+                    field_inits.push(quote! {
+                        #field_name: #value
+                    });
+                }
 
-                quote! {
-                    #enum_ident::#variant_ident { #(#field_names),* } => {
+                match_arms.push(quote! {
+                    #enum_ident::#variant_ident { #(#field_patterns),* } => {
                         #enum_ident::#variant_ident {
                             #(#field_inits),*
                         }
                     }
-                }
+                });
             }
             Fields::Unnamed(fields_unnamed) => {
                 // Generate patterns and transformations for unnamed fields
-                let field_names: Vec<_> = (0..fields_unnamed.unnamed.len())
-                    .map(|i| quote::format_ident!("field_{}", i))
-                    .collect();
-
-                let field_inits = fields_unnamed.unnamed.iter().enumerate().map(|(i, field)| {
-                    let field_name = &field_names[i];
-                    let has_lifetime = type_contains_any_lifetime(&field.ty, lifetime_params);
-
-                    if has_lifetime {
-                        quote! {
-                            ::serde_poly::OwnablePoly::into_owned(#field_name)
-                        }
-                    } else {
-                        quote! {
-                            #field_name
-                        }
-                    }
-                });
+                let mut field_patterns = Vec::new();
+                let mut field_inits = Vec::new();
+                for (i, field) in fields_unnamed.unnamed.iter().enumerate() {
+                    let field_name = quote::format_ident!("field_{}", i);
+                    field_inits.push(owned_value(
+                        krate,
+                        field,
+                        quote!(#field_name),
+                        lifetime_params,
+                        bounded_params,
+                    )?);
+                    field_patterns.push(match is_skipped(field)? {
+                        true => quote!(_),
+                        false => quote!(#field_name),
+                    });
+                }
 
-                quote! {
-                    #enum_ident::#variant_ident(#(#field_names),*) => {
+                match_arms.push(quote! {
+                    #enum_ident::#variant_ident(#(#field_patterns),*) => {
                         #enum_ident::#variant_ident(
                             #(#field_inits),*
                         )
                     }
-                }
+                });
             }
             Fields::Unit => {
-                quote! {
+                match_arms.push(quote! {
                     #enum_ident::#variant_ident => #enum_ident::#variant_ident
-                }
+                });
             }
         }
-    });
+    }
 
     Ok(quote! {
         match self {
@@ -182,25 +336,27 @@ fn generate_enum_transformation(
 }
 
 fn generate_field_transformations(
+    krate: &Path,
     fields: &Fields,
     lifetime_params: &[Lifetime],
+    bounded_params: &[Ident],
 ) -> syn::Result<TokenStream2> {
     match fields {
         Fields::Named(fields_named) => {
-            let field_inits = fields_named.named.iter().map(|field| {
+            let mut field_inits = Vec::new();
+            for field in &fields_named.named {
                 let field_name = field.ident.as_ref().unwrap();
-                let has_lifetime = type_contains_any_lifetime(&field.ty, lifetime_params);
-
-                if has_lifetime {
-                    quote! {
-                        #field_name: ::serde_poly::OwnablePoly::into_owned(self.#field_name)
-                    }
-                } else {
-                    quote! {
-                        #field_name: self.#field_name
-                    }
-                }
-            });
+                let value = owned_value(
+                    krate,
+                    field,
+                    quote!(self.#field_name),
+                    lifetime_params,
+                    bounded_params,
+                )?;
+                field_inits.push(quote! {
+                    #field_name: #value
+                });
+            }
 
             Ok(quote! {
                 {
@@ -209,24 +365,17 @@ fn generate_field_transformations(
             })
         }
         Fields::Unnamed(fields_unnamed) => {
-            let field_inits = fields_unnamed
-                .unnamed
-                .iter()
-                .enumerate()
-                .map(|(i, field)| {
-                    let index = syn::Index::from(i);
-                    let has_lifetime = type_contains_any_lifetime(&field.ty, lifetime_params);
-
-                    if has_lifetime {
-                        quote! {
-                            ::serde_poly::OwnablePoly::into_owned(self.#index)
-                        }
-                    } else {
-                        quote! {
-                            self.#index
-                        }
-                    }
-                });
+            let mut field_inits = Vec::new();
+            for (i, field) in fields_unnamed.unnamed.iter().enumerate() {
+                let index = syn::Index::from(i);
+                field_inits.push(owned_value(
+                    krate,
+                    field,
+                    quote!(self.#index),
+                    lifetime_params,
+                    bounded_params,
+                )?);
+            }
 
             Ok(quote! {
                 (
@@ -238,84 +387,320 @@ fn generate_field_transformations(
     }
 }
 
-/// Check if a type contains any of the specified lifetimes
-fn type_contains_any_lifetime(ty: &Type, lifetimes: &[Lifetime]) -> bool {
+/// The owned value of a field, given the expression moving it out of the value. Fields borrowing
+/// the type's lifetimes or mentioning the bounded type parameters are converted, `#[ownable(skip)]`
+/// fields are replaced, and the rest, including `#[ownable(clone)]` fields, are moved through.
+fn owned_value(
+    krate: &Path,
+    field: &syn::Field,
+    value: TokenStream2,
+    lifetime_params: &[Lifetime],
+    bounded_params: &[Ident],
+) -> syn::Result<TokenStream2> {
+    let field_attrs = parse_ownable_field_attrs(&field.attrs)?;
+    if let Some(skip) = field_attrs.skip {
+        return Ok(match skip.default {
+            Some(default) => quote!(#default),
+            None => quote!(::core::default::Default::default()),
+        });
+    }
+    // A marker carries no data, so it's rebuilt, whatever it marks.
+    if is_phantom_data(&field.ty) {
+        return Ok(quote!(::core::marker::PhantomData));
+    }
+    let converted = type_contains_any_lifetime(&field.ty, lifetime_params)
+        || (field_attrs.clone.is_none() && is_generic_data(&field.ty, bounded_params));
+    Ok(match converted {
+        true => quote!(#krate::OwnablePoly::into_owned(#value)),
+        false => value,
+    })
+}
+
+/// Whether a field holds data of the bounded type parameters, which `PhantomData` doesn't.
+fn is_generic_data(ty: &Type, bounded_params: &[Ident]) -> bool {
+    type_mentions_any(ty, bounded_params) && !is_phantom_data(ty)
+}
+
+fn is_skipped(field: &syn::Field) -> syn::Result<bool> {
+    Ok(parse_ownable_field_attrs(&field.attrs)?.skip.is_some())
+}
+
+pub(crate) fn all_fields(data: &Data) -> Vec<&syn::Field> {
+    match data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    }
+}
+
+/// Collect the associated type projections of type parameters within a type, such as `P::Out<'a>`
+/// or `<P as DeserializePoly>::Out<'a>`.
+fn collect_projections<'t>(ty: &'t Type, type_params: &[Ident], projections: &mut Vec<&'t Type>) {
     match ty {
-        Type::Reference(type_ref) => {
-            // Check if the reference's lifetime matches any of our lifetimes
-            if let Some(ref lt) = type_ref.lifetime {
-                if lifetimes.iter().any(|param_lt| lt.ident == param_lt.ident) {
-                    return true;
-                }
-            }
-            // Recursively check the referenced type
-            type_contains_any_lifetime(&type_ref.elem, lifetimes)
-        }
         Type::Path(type_path) => {
-            // Check if any generic arguments contain our lifetimes
+            let is_projection = type_path.qself.is_some()
+                || (type_path.path.segments.len() > 1
+                    && type_path.path.leading_colon.is_none()
+                    && type_params.contains(&type_path.path.segments[0].ident));
+            if is_projection {
+                projections.push(ty);
+                return;
+            }
             for segment in &type_path.path.segments {
-                match &segment.arguments {
-                    PathArguments::AngleBracketed(args) => {
-                        for arg in &args.args {
-                            match arg {
-                                GenericArgument::Lifetime(lt) => {
-                                    if lifetimes.iter().any(|param_lt| lt.ident == param_lt.ident)
-                                    {
-                                        return true;
-                                    }
-                                }
-                                GenericArgument::Type(inner_ty) => {
-                                    if type_contains_any_lifetime(inner_ty, lifetimes) {
-                                        return true;
-                                    }
-                                }
-                                GenericArgument::AssocType(assoc) => {
-                                    if type_contains_any_lifetime(&assoc.ty, lifetimes) {
-                                        return true;
-                                    }
-                                }
-                                GenericArgument::Constraint(constraint) => {
-                                    for bound in &constraint.bounds {
-                                        if let syn::TypeParamBound::Lifetime(lt) = bound {
-                                            if lifetimes
-                                                .iter()
-                                                .any(|param_lt| lt.ident == param_lt.ident)
-                                            {
-                                                return true;
-                                            }
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(inner_ty) = arg {
+                            collect_projections(inner_ty, type_params, projections);
                         }
                     }
-                    PathArguments::Parenthesized(args) => {
-                        for input in &args.inputs {
-                            if type_contains_any_lifetime(input, lifetimes) {
-                                return true;
-                            }
-                        }
-                        if let syn::ReturnType::Type(_, ty) = &args.output {
-                            if type_contains_any_lifetime(ty, lifetimes) {
-                                return true;
-                            }
-                        }
-                    }
-                    PathArguments::None => {}
                 }
             }
-            false
         }
-        Type::Tuple(type_tuple) => type_tuple
-            .elems
-            .iter()
-            .any(|elem| type_contains_any_lifetime(elem, lifetimes)),
-        Type::Array(type_array) => type_contains_any_lifetime(&type_array.elem, lifetimes),
-        Type::Ptr(type_ptr) => type_contains_any_lifetime(&type_ptr.elem, lifetimes),
-        Type::Slice(type_slice) => type_contains_any_lifetime(&type_slice.elem, lifetimes),
-        Type::Paren(type_paren) => type_contains_any_lifetime(&type_paren.elem, lifetimes),
-        Type::Group(type_group) => type_contains_any_lifetime(&type_group.elem, lifetimes),
-        _ => false,
+        Type::Reference(type_ref) => collect_projections(&type_ref.elem, type_params, projections),
+        Type::Tuple(type_tuple) => {
+            for elem in &type_tuple.elems {
+                collect_projections(elem, type_params, projections);
+            }
+        }
+        Type::Array(type_array) => collect_projections(&type_array.elem, type_params, projections),
+        Type::Slice(type_slice) => collect_projections(&type_slice.elem, type_params, projections),
+        Type::Paren(type_paren) => collect_projections(&type_paren.elem, type_params, projections),
+        Type::Group(type_group) => collect_projections(&type_group.elem, type_params, projections),
+        _ => {}
+    }
+}
+
+/// A fn pointer or trait object borrowing one of the type's lifetimes has no owned form, and a
+/// reference has none of the same type, which would otherwise surface as a missing `OwnablePoly`
+/// impl for the whole field type.
+fn erased_borrow_check(data: &Data, lifetime_params: &[Lifetime]) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    for field in all_fields(data) {
+        if is_skipped(field)? || is_phantom_data(&field.ty) {
+            continue;
+        }
+        let error = if let Some(ty) = find_erased_borrow(&field.ty, lifetime_params) {
+            let kind = match ty {
+                Type::BareFn(_) => "fn pointer",
+                _ => "trait object",
+            };
+            syn::Error::new_spanned(
+                ty,
+                format!(
+                    "OwnablePoly can't convert a {} borrowing the type's lifetimes to 'static, \
+                     make it 'static or implement OwnablePoly by hand",
+                    kind
+                ),
+            )
+        } else if let Some(type_ref) = find_borrowed_reference(&field.ty, lifetime_params) {
+            let lifetime = &type_ref.lifetime;
+            let elem = &type_ref.elem;
+            syn::Error::new_spanned(
+                type_ref,
+                format!(
+                    "OwnablePoly can't convert `{}` to 'static without changing its type, use \
+                     `Cow<{}, {}>` instead, #[derive(ToStatic)] for an owned sibling type, or \
+                     #[ownable(skip)] if the field carries no data",
+                    type_ref.to_token_stream().to_string().replace("& ", "&"),
+                    lifetime.to_token_stream(),
+                    elem.to_token_stream(),
+                ),
+            )
+        } else {
+            continue;
+        };
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// A trait bound on a type parameter naming the type's lifetimes, such as `S: Schema<'b>`, would
+/// need restating as `S: Schema<'static>` for the owned type, which the compiler can't tell apart
+/// from the original bound, so such bounds must be higher-ranked instead. Outlives bounds between
+/// the lifetimes, or on type parameters, are fine.
+fn lifetime_bound_check(generics: &syn::Generics, lifetime_params: &[Lifetime]) -> syn::Result<()> {
+    let mut bounds = Vec::new();
+    for param in &generics.params {
+        if let GenericParam::Type(type_param) = param {
+            bounds.extend(
+                type_param
+                    .bounds
+                    .iter()
+                    .map(|bound| (&type_param.ident, bound)),
+            );
+        }
     }
-}
\ No newline at end of file
+    let where_predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates);
+    let mut bounded_types = Vec::new();
+    for predicate in where_predicates {
+        if let syn::WherePredicate::Type(predicate_type) = predicate {
+            if predicate_type.lifetimes.is_none() {
+                bounded_types.push(predicate_type);
+            }
+        }
+    }
+    let mut errors: Option<syn::Error> = None;
+    let mut check = |bounded: &dyn ToTokens, bound: &syn::TypeParamBound| {
+        if !matches!(bound, syn::TypeParamBound::Trait(_))
+            || !bound_contains_any_lifetime(bound, lifetime_params)
+        {
+            return;
+        }
+        let error = syn::Error::new_spanned(
+            bound,
+            format!(
+                "OwnablePoly can't restate `{}: {}` for the owned type's 'static lifetimes, bound \
+                 it for all lifetimes instead, e.g. `for<'x> {}: {}`",
+                tidy(bounded),
+                tidy(bound),
+                tidy(bounded),
+                tidy(&respelled(bound, lifetime_params)),
+            ),
+        );
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    };
+    for (ident, bound) in bounds {
+        check(ident, bound);
+    }
+    for predicate_type in bounded_types {
+        for bound in &predicate_type.bounds {
+            check(&predicate_type.bounded_ty, bound);
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// A bound with the type's lifetimes renamed to `'x`, for suggesting a higher-ranked bound.
+fn respelled(bound: &syn::TypeParamBound, lifetime_params: &[Lifetime]) -> syn::TypeParamBound {
+    let mut bound = bound.clone();
+    MapLifetimes::new(|lifetime: &Lifetime| {
+        lifetime_params
+            .contains(lifetime)
+            .then(|| Lifetime::new("'x", lifetime.span()))
+    })
+    .visit_type_param_bound_mut(&mut bound);
+    bound
+}
+
+/// Tokens as a string for an error message, without the spaces around angle brackets.
+fn tidy(tokens: &dyn ToTokens) -> String {
+    tokens
+        .to_token_stream()
+        .to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+}
+
+/// The type's `#[ownable(...)]` attributes.
+#[derive(Default)]
+struct OwnableAttrs {
+    /// Where-predicates added to the inferred ones.
+    bound: Vec<syn::WherePredicate>,
+    /// Don't bound generic fields on converting to their `'static` form.
+    no_bound: bool,
+}
+
+fn parse_ownable_attrs(attrs: &[Attribute]) -> syn::Result<OwnableAttrs> {
+    let mut ownable_attrs = OwnableAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ownable")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                ownable_attrs
+                    .bound
+                    .extend(parse_bound(&meta.value()?.parse()?)?);
+                Ok(())
+            } else if meta.path.is_ident("no_bound") {
+                ownable_attrs.no_bound = true;
+                Ok(())
+            } else {
+                let key = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format!(
+                    "unsupported ownable attribute `{}`, expected bound or no_bound",
+                    key
+                )))
+            }
+        })?;
+    }
+    Ok(ownable_attrs)
+}
+
+/// A field's `#[ownable(...)]` attributes.
+#[derive(Default)]
+struct OwnableFieldAttrs {
+    /// Move the field through as-is, for data that's already `'static`.
+    clone: Option<Span>,
+    /// Replace the field rather than converting it, for fields that carry no data.
+    skip: Option<Skip>,
+}
+
+struct Skip {
+    span: Span,
+    /// The `#[ownable(skip = "...")]` expression, or `Default::default()` if not given.
+    default: Option<Expr>,
+}
+
+fn parse_ownable_field_attrs(attrs: &[Attribute]) -> syn::Result<OwnableFieldAttrs> {
+    let mut field_attrs = OwnableFieldAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ownable")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("clone") {
+                field_attrs.clone = Some(meta.path.span());
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                let span = meta.path.span();
+                let default = match meta.input.peek(Token![=]) {
+                    true => Some(meta.value()?.parse::<syn::LitStr>()?.parse::<Expr>()?),
+                    false => None,
+                };
+                field_attrs.skip = Some(Skip { span, default });
+                Ok(())
+            } else {
+                let key = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format!(
+                    "unsupported ownable attribute `{}`, expected clone or skip",
+                    key
+                )))
+            }
+        })?;
+    }
+    Ok(field_attrs)
+}
+
+/// Read the `#[poly(crate = "...")]` path shared with the Poly derive, ignoring its other keys.
+pub(crate) fn parse_crate_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    for attr in attrs {
+        if !attr.path().is_ident("poly") {
+            continue;
+        }
+
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if let Meta::NameValue(name_value) = meta {
+                if name_value.path.is_ident("crate") {
+                    return match name_value.value {
+                        Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(lit), ..
+                        }) => lit.parse(),
+                        value => Err(syn::Error::new(value.span(), "expected a string literal")),
+                    };
+                }
+            }
+        }
+    }
+
+    Ok(syn::parse_quote!(::serde_poly))
+}
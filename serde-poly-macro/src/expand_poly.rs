@@ -1,19 +1,65 @@
-use proc_macro2::{Span, TokenStream as TokenStream2};
-use quote::{format_ident, quote};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{
-    punctuated::Punctuated,
-    spanned::Spanned,
-    Attribute,
-    DeriveInput,
-    GenericParam,
-    Ident,
-    LitStr,
-    Meta,
-    Token,
-    Visibility,
+    Attribute, Data, DeriveInput, Field, GenericParam, Ident, Lifetime, LitStr, Meta, Token, Type,
+    TypeParamBound, Visibility, WherePredicate, ext::IdentExt, punctuated::Punctuated,
+    spanned::Spanned, visit_mut::VisitMut,
 };
 
-pub fn expand_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
+use crate::expand_ownable_poly::expand_ownable_poly;
+use crate::lifetimes::{MapLifetimes, type_contains_any_lifetime};
+
+/// The keys accepted in `#[poly(...)]`, listed in the error for unknown keys.
+const POLY_ATTRIBUTES: &[&str] = &[
+    "allow_unborrowed",
+    "bound",
+    "crate",
+    "derive",
+    "doc",
+    "force_marker",
+    "lifetime",
+    "marker_fn",
+    "module",
+    "name",
+    "no_bound",
+    "ownable",
+    "phantom_lifetime",
+    "prefix",
+    "suffix",
+    "tag",
+    "test",
+    "transparent",
+    "uninhabited",
+    "upgrade_from",
+    "use_marker",
+    "variance",
+    "version",
+    "vis",
+];
+
+/// How the marker's `PhantomData` uses its type parameters, from `#[poly(variance = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Variance {
+    Covariant,
+    Contravariant,
+    Invariant,
+}
+
+/// Which of the poly trait impls a derive emits.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PolyImpls {
+    Both,
+    SerializeOnly,
+    DeserializeOnly,
+}
+
+/// Expand the derive. A `remote` path names the type that `Out<'de>` deserializes to, for
+/// `poly_remote!`, with `input` declaring its generics.
+pub fn expand_poly(
+    input: DeriveInput,
+    impls: PolyImpls,
+    remote: Option<syn::Path>,
+) -> syn::Result<TokenStream2> {
     if let syn::Data::Union(data_union) = &input.data {
         return Err(syn::Error::new(
             data_union.union_token.span,
@@ -21,15 +67,71 @@ pub fn expand_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
         ));
     }
 
+    let poly_attrs = parse_poly_attrs(&input.attrs)?;
+    let ownable_impl = match poly_attrs.ownable {
+        Some(_) => Some(expand_ownable_poly(input.clone())?),
+        None => None,
+    };
+
     let DeriveInput {
         attrs,
         vis,
         ident,
         generics,
-        ..
+        data,
     } = input;
 
-    let poly_name = parse_poly_name(&attrs, &ident)?;
+    let krate = poly_attrs
+        .krate
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote!(::serde_poly));
+
+    let tag_impl = poly_attrs.tag.as_ref().map(|tag| {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #krate::PolyTag for #ident #ty_generics #where_clause {
+                const TAG: &'static str = #tag;
+            }
+        }
+    });
+
+    let version_impl = match &poly_attrs.version {
+        Some(version) if poly_attrs.transparent.is_some() => {
+            return Err(syn::Error::new(
+                version.span(),
+                "poly(version) can't be combined with poly(transparent)",
+            ));
+        }
+        Some(version) => Some(expand_version(
+            &krate,
+            &ident,
+            &generics,
+            version,
+            poly_attrs.upgrade_from.as_ref(),
+        )?),
+        None => None,
+    };
+
+    let nested_json_accessors = nested_json_accessors(
+        &krate,
+        &vis,
+        &ident,
+        &generics,
+        &data,
+        impls,
+        remote.is_some(),
+    )?;
+
+    if poly_attrs.transparent.is_some() {
+        let transparent_impls =
+            expand_transparent(&poly_attrs, &krate, &attrs, &ident, &generics, &data, impls)?;
+        return Ok(quote! {
+            #transparent_impls
+            #nested_json_accessors
+            #tag_impl
+            #ownable_impl
+        });
+    }
 
     let lifetime_params: Vec<_> = generics
         .params
@@ -40,35 +142,219 @@ pub fn expand_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
         })
         .collect();
 
-    if lifetime_params.len() > 1 {
-        let offending = &lifetime_params[1];
-        return Err(syn::Error::new(
-            offending.span(),
-            "Poly derive supports at most one lifetime parameter",
-        ));
+    // A lifetime bounded by `'static` can't borrow from the deserializer, so it's never bound to
+    // `'de` and only the others compete for it.
+    let static_lifetimes = static_lifetimes(&generics);
+    let borrow_lifetimes: Vec<_> = lifetime_params
+        .iter()
+        .filter(|lifetime| !static_lifetimes.contains(&lifetime.ident))
+        .cloned()
+        .collect();
+
+    // The lifetime bound to `'de` in `Out<'de>`; any other lifetimes are fixed to `'static`.
+    let de_lifetime = match &poly_attrs.lifetime {
+        Some(lifetime) if static_lifetimes.contains(&lifetime.ident) => {
+            return Err(syn::Error::new(
+                lifetime.span(),
+                format!(
+                    "poly(lifetime = \"{}\") is bounded by 'static, so it can't borrow from the \
+                     deserializer",
+                    lifetime
+                ),
+            ));
+        }
+        Some(lifetime) => {
+            if !lifetime_params
+                .iter()
+                .any(|param| param.ident == lifetime.ident)
+            {
+                let declared: Vec<_> = lifetime_params
+                    .iter()
+                    .map(|param| format!("`{}`", param))
+                    .collect();
+                let expected = match declared.is_empty() {
+                    true => format!("`{}` declares no lifetimes", ident),
+                    false => format!("expected one of {}", declared.join(", ")),
+                };
+                return Err(syn::Error::new(
+                    lifetime.span(),
+                    format!(
+                        "poly(lifetime = \"...\") must name one of the type's lifetime parameters, \
+                         {}",
+                        expected
+                    ),
+                ));
+            }
+            Some(lifetime.clone())
+        }
+        None => {
+            if borrow_lifetimes.len() > 1 && poly_attrs.phantom_lifetime.is_none() {
+                return Err(multiple_lifetimes_error(&borrow_lifetimes));
+            }
+            borrow_lifetimes.first().cloned()
+        }
+    };
+
+    if borrow_lifetimes.is_empty() {
+        if let Some(name) = &poly_attrs.name {
+            return Err(syn::Error::new(
+                name.span(),
+                "poly(name = \"...\") is only valid for types with a lifetime parameter",
+            ));
+        }
+        if let Some(marker) = &poly_attrs.use_marker {
+            return Err(syn::Error::new(
+                marker.span(),
+                "poly(use_marker = \"...\") is only valid for types with a lifetime parameter",
+            ));
+        }
+        if let Some(span) = poly_attrs.force_marker {
+            return Err(syn::Error::new(
+                span,
+                "poly(force_marker) is only valid for types with a lifetime parameter",
+            ));
+        }
+        if let Some(span) = poly_attrs.phantom_lifetime {
+            return Err(syn::Error::new(
+                span,
+                "poly(phantom_lifetime) is only valid for types with a lifetime parameter",
+            ));
+        }
     }
 
-    let has_lifetime = !lifetime_params.is_empty();
-    if !has_lifetime {
-        if let Some(span) = poly_name.attr_span {
+    if let Some(span) = poly_attrs.phantom_lifetime {
+        if poly_attrs.name.is_some() || poly_attrs.force_marker.is_some() {
             return Err(syn::Error::new(
                 span,
-                "poly(name = \"...\") is only valid for types with a single lifetime parameter",
+                "poly(phantom_lifetime) treats the type as owned, which has no Poly type to name \
+                 or force",
             ));
         }
     }
 
-    let poly_ident = poly_name.ident;
+    // A lifetime that only appears in `PhantomData` fields never borrows deserialized data, so the
+    // type can implement the traits with `Self` like an owned type unless a marker is requested.
+    // `poly(phantom_lifetime)` asserts the same for lifetimes hidden elsewhere, e.g. skipped fields.
+    let has_lifetime = match &de_lifetime {
+        Some(_) if poly_attrs.phantom_lifetime.is_some() => false,
+        Some(lifetime) => {
+            poly_attrs.name.is_some()
+                || poly_attrs.use_marker.is_some()
+                || poly_attrs.force_marker.is_some()
+                || lifetime_is_used(&data, lifetime)
+        }
+        None => false,
+    };
+
+    if let (true, Some(lifetime), None) = (
+        has_lifetime && impls != PolyImpls::SerializeOnly,
+        &de_lifetime,
+        poly_attrs.allow_unborrowed,
+    ) {
+        unborrowed_cow_check(&data, lifetime)?;
+    }
+    if let (true, Some(lifetime)) = (
+        has_lifetime && impls != PolyImpls::SerializeOnly,
+        &de_lifetime,
+    ) {
+        let fixed: Vec<_> = borrow_lifetimes
+            .iter()
+            .filter(|fixed| fixed.ident != lifetime.ident)
+            .cloned()
+            .collect();
+        fixed_lifetime_check(&data, &fixed, lifetime)?;
+    }
 
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    if let (false, Some(span)) = (has_lifetime, poly_attrs.derive_span) {
+        return Err(syn::Error::new(
+            span,
+            "poly(derive(...)) needs a generated Poly type, which owned types don't have",
+        ));
+    }
+    for (key, affix) in [
+        ("prefix", &poly_attrs.prefix),
+        ("suffix", &poly_attrs.suffix),
+    ] {
+        if let (false, Some(affix)) = (has_lifetime, affix) {
+            return Err(syn::Error::new(
+                affix.span(),
+                format!(
+                    "poly({} = \"...\") needs a generated Poly type, which owned types don't have",
+                    key
+                ),
+            ));
+        }
+    }
+    if let (false, Some(span)) = (has_lifetime, poly_attrs.uninhabited) {
+        return Err(syn::Error::new(
+            span,
+            "poly(uninhabited) needs a generated Poly type, which owned types don't have",
+        ));
+    }
+    if let (false, Some(span)) = (has_lifetime, poly_attrs.marker_fn) {
+        return Err(syn::Error::new(
+            span,
+            "poly(marker_fn) needs a generated Poly type, which owned types don't have",
+        ));
+    }
+    if let (Some(span), Some(_)) = (poly_attrs.marker_fn, poly_attrs.uninhabited) {
+        return Err(syn::Error::new(
+            span,
+            "poly(marker_fn) returns a Poly type value, which poly(uninhabited) has none of",
+        ));
+    }
+    if let (Some(span), Some(_)) = (poly_attrs.marker_fn, &remote) {
+        return Err(syn::Error::new(
+            span,
+            "poly(marker_fn) adds an inherent method, which a remote type can't have",
+        ));
+    }
+    if let (false, Some((_, span))) = (has_lifetime, poly_attrs.variance) {
+        return Err(syn::Error::new(
+            span,
+            "poly(variance = \"...\") needs a generated Poly type, which owned types don't have",
+        ));
+    }
+    if let (false, Some(module)) = (has_lifetime, &poly_attrs.module) {
+        return Err(syn::Error::new(
+            module.span(),
+            "poly(module = \"...\") needs a generated Poly type, which owned types don't have",
+        ));
+    }
+
+    let poly_ident = match poly_attrs.name {
+        Some(ident) => ident,
+        None => poly_name(
+            &ident,
+            poly_attrs.prefix.as_ref(),
+            poly_attrs.suffix.as_ref(),
+        )?,
+    };
 
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    // The marker is only a phantom, so its declaration drops the bounds on the type parameters.
+    // They are carried over to its `DeserializePoly` impl instead, where they are needed.
     let mut poly_generics = generics.clone();
     poly_generics.params.clear();
     let mut poly_params = Punctuated::<GenericParam, Token![,]>::new();
-    for param in generics.params.iter().cloned() {
-        if matches!(param, GenericParam::Lifetime(_)) {
-            continue;
+    for mut param in generics.params.iter().cloned() {
+        match &mut param {
+            GenericParam::Lifetime(_) => continue,
+            GenericParam::Type(ty) => {
+                ty.colon_token = None;
+                ty.bounds.clear();
+            }
+            GenericParam::Const(_) => {}
         }
+        // Defaults are kept so `XPoly` can be named without its defaulted parameters, with the
+        // type's lifetimes, which the marker doesn't have, fixed to `'static`.
+        MapLifetimes::new(|lifetime: &Lifetime| {
+            lifetime_params
+                .contains(lifetime)
+                .then(|| Lifetime::new("'static", lifetime.span()))
+        })
+        .visit_generic_param_mut(&mut param);
         poly_params.push(param);
     }
     poly_generics.params = poly_params;
@@ -97,46 +383,95 @@ pub fn expand_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
         })
         .collect();
 
-    let serialize_out = if has_lifetime {
+    let poly_path = match &poly_attrs.module {
+        Some(module) => quote!(#module::#poly_ident),
+        None => quote!(#poly_ident),
+    };
+    let serialize_out = if let Some(marker) = &poly_attrs.use_marker {
+        quote!(#marker)
+    } else if has_lifetime {
         if poly_ty_args.is_empty() {
-            quote!(#poly_ident)
+            quote!(#poly_path)
         } else {
-            quote!(#poly_ident < #(#poly_ty_args),* >)
+            quote!(#poly_path < #(#poly_ty_args),* >)
         }
     } else {
         quote!(Self)
     };
 
-    let mut out_ty_args: Vec<TokenStream2> = Vec::new();
-    if has_lifetime {
-        for param in &generics.params {
-            match param {
-                GenericParam::Lifetime(_) => out_ty_args.push(quote!('de)),
+    let out_path = match &remote {
+        Some(path) => quote!(#path),
+        None => quote!(#ident),
+    };
+    // `Out<'de>` for the marker, with the deserializer lifetime named `de`.
+    let out_ty = |de: &TokenStream2| {
+        let args: Vec<TokenStream2> = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Lifetime(lt) => {
+                    if de_lifetime.as_ref() == Some(&lt.lifetime) {
+                        de.clone()
+                    } else {
+                        quote!('static)
+                    }
+                }
                 GenericParam::Type(ty) => {
                     let ident = &ty.ident;
-                    out_ty_args.push(quote!(#ident));
+                    quote!(#ident)
                 }
                 GenericParam::Const(konst) => {
                     let ident = &konst.ident;
-                    out_ty_args.push(quote!(#ident));
+                    quote!(#ident)
                 }
-            }
+            })
+            .collect();
+        if args.is_empty() {
+            quote!(#out_path)
+        } else {
+            quote!(#out_path < #(#args),* >)
         }
-    }
+    };
 
     let deserialize_out = if has_lifetime {
-        if out_ty_args.is_empty() {
-            quote!(#ident)
-        } else {
-            quote!(#ident < #(#out_ty_args),* >)
-        }
+        out_ty(&quote!('de))
     } else {
         quote!(Self)
     };
 
     let (poly_impl_generics, poly_ty_generics, poly_where_clause) = poly_generics.split_for_impl();
 
-    let poly_items = if has_lifetime {
+    // Associated types can't be less visible than their impl, so a marker with an overridden
+    // visibility is declared `pub` in a hidden module and re-exported with the requested one. A
+    // marker in a named module is likewise `pub`, with the module taking the visibility instead.
+    let (vis, reexport_vis) = match (poly_attrs.vis, &poly_attrs.module) {
+        (Some(reexport_vis), _) => (Visibility::Public(Default::default()), Some(reexport_vis)),
+        (None, Some(_)) => (Visibility::Public(Default::default()), Some(vis)),
+        (None, None) => (vis, None),
+    };
+    // `#[poly(marker_fn)]` adds `T::marker()`, naming the generated Poly type with the same
+    // visibility for call sites that don't know its name. It's opt-in, as the inherent method could
+    // clash with one of the type's own.
+    let marker_fn = poly_attrs.marker_fn.map(|_| {
+        let marker_vis = reexport_vis.clone().unwrap_or_else(|| vis.clone());
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let doc = format!("The Poly type for `{}`.", ident);
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc = #doc]
+                #marker_vis const fn marker() -> #serialize_out {
+                    <#serialize_out>::new()
+                }
+            }
+        }
+    });
+
+    // An existing Poly type given with `use_marker` takes the generated one's place in the impls.
+    let marker_ty = match &poly_attrs.use_marker {
+        Some(marker) => quote!(#marker),
+        None => quote!(#poly_path #poly_ty_generics),
+    };
+    let poly_items = if has_lifetime && poly_attrs.use_marker.is_none() {
         let field_vis: TokenStream2 = match &vis {
             Visibility::Inherited => TokenStream2::new(),
             Visibility::Public(_) => quote!(pub),
@@ -146,83 +481,577 @@ pub fn expand_poly(input: DeriveInput) -> syn::Result<TokenStream2> {
             }
         };
 
-        let phantom_fields: Vec<_> = poly_generics
+        let phantom_types: Vec<_> = poly_generics
             .params
             .iter()
-            .map(|param| {
-                let field_vis = field_vis.clone();
-                match param {
-                    GenericParam::Type(ty) => {
-                        let ident = &ty.ident;
-                        quote!(#field_vis ::core::marker::PhantomData<fn() -> #ident>)
+            .map(|param| match param {
+                // Function pointers keep the marker `Send`, `Sync` and free of drop checks whatever
+                // the variance.
+                GenericParam::Type(ty) => {
+                    let ident = &ty.ident;
+                    match poly_attrs.variance.map(|(variance, _)| variance) {
+                        None | Some(Variance::Covariant) => {
+                            quote!(::core::marker::PhantomData<fn() -> #ident>)
+                        }
+                        Some(Variance::Contravariant) => {
+                            quote!(::core::marker::PhantomData<fn(#ident)>)
+                        }
+                        Some(Variance::Invariant) => {
+                            quote!(::core::marker::PhantomData<fn(#ident) -> #ident>)
+                        }
                     }
-                    GenericParam::Const(_) => {
-                        quote!(#field_vis ::core::marker::PhantomData<fn() -> ()>)
+                }
+                GenericParam::Const(_) => quote!(::core::marker::PhantomData<fn() -> ()>),
+                GenericParam::Lifetime(_) => unreachable!(),
+            })
+            .collect();
+        let phantom_fields: Vec<_> = poly_generics
+            .params
+            .iter()
+            .zip(&phantom_types)
+            .map(|(param, phantom_type)| {
+                let doc = match param {
+                    GenericParam::Type(ty) => format!("Marks the `{}` type parameter.", ty.ident),
+                    GenericParam::Const(konst) => {
+                        format!("Marks the `{}` const parameter.", konst.ident)
                     }
                     GenericParam::Lifetime(_) => unreachable!(),
-                }
+                };
+                quote!(#[doc = #doc] #field_vis #phantom_type)
             })
             .collect();
 
-        Some(quote! {
-            #vis struct #poly_ident #poly_generics_decl ( #(#phantom_fields),* );
+        // These are always implemented below without bounds on the type parameters, so forwarding
+        // them as derives would conflict.
+        let derives: Vec<_> = poly_attrs
+            .derives
+            .iter()
+            .filter(|path| {
+                !path.segments.last().is_some_and(|segment| {
+                    [
+                        "Clone",
+                        "Copy",
+                        "Debug",
+                        "Default",
+                        "PartialEq",
+                        "Eq",
+                        "PartialOrd",
+                        "Ord",
+                        "Hash",
+                    ]
+                    .iter()
+                    .any(|name| segment.ident == name)
+                })
+            })
+            .collect();
+        let derive_attr = (!derives.is_empty()).then(|| quote!(#[derive(#(#derives),*)]));
+        let phantom_values = phantom_fields
+            .iter()
+            .map(|_| quote!(::core::marker::PhantomData));
+
+        let doc_target = out_path.to_string().replace(' ', "");
+        let doc = match &poly_attrs.doc {
+            Some(doc) => doc.value(),
+            None => format!("The Poly marker for [`{}`].", doc_target),
+        };
+        let new_doc = format!("Creates a `{}`.", poly_ident);
+
+        let uninhabited = poly_attrs.uninhabited.is_some();
+        let poly_decl = if uninhabited {
+            quote! {
+                #[doc = #doc]
+                #derive_attr
+                #vis enum #poly_ident #poly_generics_decl {
+                    #[doc(hidden)]
+                    __Uninhabited(::core::convert::Infallible, #(#phantom_types),*),
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #derive_attr
+                #vis struct #poly_ident #poly_generics_decl ( #(#phantom_fields),* );
+
+                impl #poly_impl_generics #poly_ident #poly_ty_generics #poly_where_clause {
+                    #[doc = #new_doc]
+                    #vis const fn new() -> Self {
+                        Self(#(#phantom_values),*)
+                    }
+                }
+
+                impl #poly_impl_generics ::core::default::Default for #poly_ident #poly_ty_generics #poly_where_clause {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+            }
+        };
 
-            impl #poly_impl_generics ::serde::Serialize for #poly_ident #poly_ty_generics #poly_where_clause {
-                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        // An uninhabited marker has no values for these to be called with.
+        let absurd = quote! {
+            match *self {
+                Self::__Uninhabited(never, ..) => match never {},
+            }
+        };
+        let debug_body = match uninhabited {
+            true => absurd.clone(),
+            false => quote!(f.write_str(stringify!(#poly_ident))),
+        };
+        let eq_body = match uninhabited {
+            true => absurd.clone(),
+            false => quote!(true),
+        };
+        let cmp_body = match uninhabited {
+            true => absurd.clone(),
+            false => quote!(::core::cmp::Ordering::Equal),
+        };
+        let hash_body = match uninhabited {
+            true => absurd.clone(),
+            false => TokenStream2::new(),
+        };
+        let serialize_body = match uninhabited {
+            true => absurd,
+            false => quote!(serializer.serialize_unit_struct(stringify!(#poly_ident))),
+        };
+
+        let poly_struct = quote! {
+            #poly_decl
+
+            impl #poly_impl_generics ::core::clone::Clone for #poly_ident #poly_ty_generics #poly_where_clause {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl #poly_impl_generics ::core::marker::Copy for #poly_ident #poly_ty_generics #poly_where_clause {}
+
+            impl #poly_impl_generics ::core::fmt::Debug for #poly_ident #poly_ty_generics #poly_where_clause {
+                #[allow(unused_variables)]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #debug_body
+                }
+            }
+
+            // Markers are zero-sized, so every instance is equal and hashes to nothing.
+            impl #poly_impl_generics ::core::cmp::PartialEq for #poly_ident #poly_ty_generics #poly_where_clause {
+                fn eq(&self, _: &Self) -> bool {
+                    #eq_body
+                }
+            }
+
+            impl #poly_impl_generics ::core::cmp::Eq for #poly_ident #poly_ty_generics #poly_where_clause {}
+
+            impl #poly_impl_generics ::core::cmp::PartialOrd for #poly_ident #poly_ty_generics #poly_where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl #poly_impl_generics ::core::cmp::Ord for #poly_ident #poly_ty_generics #poly_where_clause {
+                fn cmp(&self, _: &Self) -> ::core::cmp::Ordering {
+                    #cmp_body
+                }
+            }
+
+            impl #poly_impl_generics ::core::hash::Hash for #poly_ident #poly_ty_generics #poly_where_clause {
+                fn hash<__H: ::core::hash::Hasher>(&self, _: &mut __H) {
+                    #hash_body
+                }
+            }
+
+            impl #poly_impl_generics #krate::__private::serde::Serialize for #poly_ident #poly_ty_generics #poly_where_clause {
+                #[allow(unused_variables)]
+                fn serialize<__S>(&self, serializer: __S) -> Result<__S::Ok, __S::Error>
                 where
-                    S: ::serde::Serializer,
+                    __S: #krate::__private::serde::Serializer,
                 {
-                    serializer.serialize_unit_struct(stringify!(#poly_ident))
+                    #serialize_body
                 }
             }
-        })
+        };
+
+        let module_doc = format!("The module holding the Poly marker for [`{}`].", doc_target);
+        match (reexport_vis, &poly_attrs.module) {
+            (Some(module_vis), Some(module)) => Some(quote! {
+                #[doc = #module_doc]
+                #module_vis mod #module {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    #poly_struct
+                }
+            }),
+            (Some(reexport_vis), None) => {
+                let module = format_ident!("__{}", poly_ident);
+                Some(quote! {
+                    #[doc(hidden)]
+                    #[allow(non_snake_case)]
+                    mod #module {
+                        #[allow(unused_imports)]
+                        use super::*;
+
+                        #poly_struct
+                    }
+
+                    #reexport_vis use #module::#poly_ident;
+                })
+            }
+            (None, _) => Some(poly_struct),
+        }
     } else {
         None
     };
 
+    // serde bounds every type parameter used by a (de)serialized field, including flattened
+    // fields, so `Out<'de>: Deserialize<'de>` and the `Serialize` supertrait only hold if the poly
+    // impls repeat those bounds.
+    let mut deserialize_generics = if has_lifetime {
+        let mut deserialize_generics = poly_generics.clone();
+        let inline_predicates = generics
+            .type_params()
+            .filter(|ty| !ty.bounds.is_empty())
+            .map(|ty| {
+                let ident = &ty.ident;
+                let bounds = &ty.bounds;
+                syn::parse_quote!(#ident: #bounds)
+            });
+        let where_predicates = generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter().cloned());
+        let predicates: Vec<WherePredicate> = inline_predicates
+            .chain(where_predicates)
+            .filter_map(|predicate| {
+                marker_predicate(&predicate, de_lifetime.as_ref(), &lifetime_params)
+            })
+            .collect();
+        if !predicates.is_empty() {
+            deserialize_generics
+                .make_where_clause()
+                .predicates
+                .extend(predicates);
+        }
+        deserialize_generics
+    } else {
+        generics.clone()
+    };
+    let mut serialize_generics = generics.clone();
+    // Bounds from `#[serde(bound)]` replace the inferred ones as they do for serde's own impls, and
+    // `#[poly(bound)]` or `#[poly(no_bound)]` replace both. Bounds on the deserializer lifetime are
+    // made higher-ranked, since the poly impls don't have it.
+    // serde reads `skip`, `with` and `bound` on enum variants too, applying them to the variant's
+    // fields.
+    let (serde_serialize_bound, serde_deserialize_bound) = serde_bounds(&attrs);
+    let variant_fields = variant_fields(&data);
+    let field_bounds: Vec<_> = variant_fields
+        .iter()
+        .map(|(_, field)| serde_bounds(&field.attrs))
+        .collect();
+    let variant_bounds: Vec<_> = match &data {
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| serde_bounds(&variant.attrs))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let forward_serialize = poly_attrs.no_bound.is_none() && poly_attrs.serialize_bound.is_none();
+    let forward_deserialize =
+        poly_attrs.no_bound.is_none() && poly_attrs.deserialize_bound.is_none();
+    let infer_serialize = forward_serialize && serde_serialize_bound.is_none();
+    let infer_deserialize = forward_deserialize && serde_deserialize_bound.is_none();
+    for type_param in generics.type_params() {
+        let param = &type_param.ident;
+        // A remote type's fields aren't known, so each of its type parameters is assumed used.
+        let used_by = |skip: &[&str], has_bound: &dyn Fn(&SerdeBounds) -> bool| {
+            remote.is_some()
+                || variant_fields
+                    .iter()
+                    .zip(&field_bounds)
+                    .any(|((variant, field), bounds)| {
+                        let variant_attrs = variant.map_or(&[][..], |variant| &variant.attrs);
+                        !is_phantom_data(&field.ty)
+                            && !has_serde_attr(&field.attrs, skip)
+                            && !has_serde_attr(variant_attrs, skip)
+                            && !has_bound(bounds)
+                            && !has_bound(&serde_bounds(variant_attrs))
+                            && type_mentions_any(&field.ty, std::slice::from_ref(param))
+                    })
+        };
+
+        if infer_deserialize
+            && used_by(
+                &["skip", "skip_deserializing", "with", "deserialize_with"],
+                &|bounds| bounds.1.is_some(),
+            )
+        {
+            deserialize_generics.make_where_clause().predicates.push(
+                syn::parse_quote!(#param: for<'de> #krate::__private::serde::Deserialize<'de>),
+            );
+        }
+        if infer_serialize
+            && used_by(
+                &["skip", "skip_serializing", "with", "serialize_with"],
+                &|bounds| bounds.0.is_some(),
+            )
+        {
+            serialize_generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#param: #krate::__private::serde::Serialize));
+        }
+    }
+
+    let mut forwarded_serialize = Vec::new();
+    let mut forwarded_deserialize = Vec::new();
+    for (serialize, deserialize) in field_bounds.iter().chain(&variant_bounds).chain([&(
+        serde_serialize_bound.clone(),
+        serde_deserialize_bound.clone(),
+    )]) {
+        forwarded_serialize.extend(serialize.iter().flatten().cloned());
+        forwarded_deserialize.extend(deserialize.iter().flatten().cloned());
+    }
+    if forward_deserialize {
+        let predicates = forwarded_deserialize
+            .iter()
+            .filter_map(|predicate| match has_lifetime {
+                true => marker_predicate(predicate, de_lifetime.as_ref(), &lifetime_params),
+                false => Some(predicate.clone()),
+            })
+            .map(higher_ranked_de);
+        deserialize_generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates);
+    }
+    if forward_serialize {
+        serialize_generics
+            .make_where_clause()
+            .predicates
+            .extend(forwarded_serialize);
+    }
+    if let Some(predicates) = &poly_attrs.deserialize_bound {
+        deserialize_generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates.iter().cloned());
+    }
+    if let Some(predicates) = &poly_attrs.serialize_bound {
+        serialize_generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates.iter().cloned());
+    }
+
+    // The trait impls would fail with errors about `Out<'de>` and the `Serialize` supertrait if the
+    // type doesn't implement serde's traits, so assert them first with errors on the type itself.
+    // The `DeserializePoly` impl is bounded on the same assertion, which implies the `Out<'de>`
+    // bound, so only the assertion's error is reported.
+    let assert_span = ident.span();
     let deserialize_impl = if has_lifetime {
+        let mut impl_generics = deserialize_generics.clone();
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(
+                for<'de> #deserialize_out: #krate::__private::PolyDeserialize<'de>
+            ));
+        let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+        // The higher-ranked `for<'de>` bounds are renamed so `'de` can be a parameter here.
+        let mut assert_generics = deserialize_generics.clone();
+        MapLifetimes::new(|lifetime: &Lifetime| {
+            (lifetime.ident == "de").then(|| Lifetime::new("'__de", lifetime.span()))
+        })
+        .visit_generics_mut(&mut assert_generics);
+        assert_generics.params.insert(0, syn::parse_quote!('de));
+        let (assert_generics, _, assert_where_clause) = assert_generics.split_for_impl();
+        let assert_out = respan(out_ty(&quote!('de)), assert_span);
+        let assertion = quote_spanned! {assert_span=>
+            #krate::__private::assert_deserialize::<#assert_out>();
+        };
         quote! {
-            impl #poly_impl_generics ::serde_poly::DeserializePoly for #poly_ident #poly_ty_generics #poly_where_clause {
+            const _: () = {
+                #[allow(dead_code, clippy::multiple_bound_locations)]
+                fn assert_deserialize #assert_generics () #assert_where_clause {
+                    #assertion
+                }
+            };
+
+            impl #impl_generics #krate::DeserializePoly for #marker_ty #where_clause {
                 type Out<'de> = #deserialize_out;
             }
         }
     } else {
+        let (assert_generics, _, assert_where_clause) = deserialize_generics.split_for_impl();
+        let assert_ty = respan(quote!(#ident #ty_generics), assert_span);
+        let assertion = quote_spanned! {assert_span=>
+            #krate::__private::assert_deserialize_owned::<#assert_ty>();
+        };
+        let mut impl_generics = deserialize_generics.clone();
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(
+                for<'de> #ident #ty_generics: #krate::__private::PolyDeserialize<'de>
+            ));
+        let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
         quote! {
-            impl #impl_generics ::serde_poly::DeserializePoly for #ident #ty_generics #where_clause {
+            const _: () = {
+                #[allow(dead_code, clippy::multiple_bound_locations)]
+                fn assert_deserialize #assert_generics () #assert_where_clause {
+                    #assertion
+                }
+            };
+
+            impl #impl_generics #krate::DeserializePoly for #ident #ty_generics #where_clause {
                 type Out<'de> = #deserialize_out;
             }
         }
     };
 
-    let serialize_impl = if has_lifetime {
+    let (impl_generics, _, where_clause) = serialize_generics.split_for_impl();
+    let assert_ty = respan(quote!(#ident #ty_generics), assert_span);
+    let assertion = quote_spanned! {assert_span=>
+        #krate::__private::assert_serialize::<#assert_ty>();
+    };
+    let serialize_impl = quote! {
+        const _: () = {
+            #[allow(dead_code, clippy::multiple_bound_locations)]
+            fn assert_serialize #impl_generics () #where_clause {
+                #assertion
+            }
+        };
+
+        impl #impl_generics #krate::SerializePoly for #ident #ty_generics #where_clause {
+            type Out = #serialize_out;
+        }
+    };
+
+    // Without type parameters, the Poly type's `DeserializePoly` bound would be checked here too,
+    // so the impl is bounded on its assertion instead, leaving the error to the assertion.
+    let mut pair_generics = generics.clone();
+    let pair_predicate: syn::WherePredicate =
+        match generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+            true => syn::parse_quote!(#serialize_out: #krate::DeserializePoly),
+            false => syn::parse_quote!(
+                for<'de> #deserialize_out: #krate::__private::PolyDeserialize<'de>
+            ),
+        };
+    pair_generics
+        .make_where_clause()
+        .predicates
+        .push(pair_predicate);
+    let (impl_generics, _, where_clause) = pair_generics.split_for_impl();
+    let pair_impl = quote! {
+        impl #impl_generics #krate::PolyPair for #ident #ty_generics #where_clause {
+            type Marker = #serialize_out;
+        }
+    };
+
+    let type_name = LitStr::new(&ident.to_string(), ident.span());
+    let marker_impl = if has_lifetime {
         quote! {
-            impl #impl_generics ::serde_poly::SerializePoly for #ident #ty_generics #where_clause {
-                type Out = #serialize_out;
+            impl #poly_impl_generics #krate::PolyMarker for #marker_ty #poly_where_clause {
+                const TYPE_NAME: &'static str = #type_name;
             }
         }
     } else {
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
         quote! {
-            impl #impl_generics ::serde_poly::SerializePoly for #ident #ty_generics #where_clause {
-                type Out = #serialize_out;
+            impl #impl_generics #krate::PolyMarker for #ident #ty_generics #where_clause {
+                const TYPE_NAME: &'static str = #type_name;
             }
         }
     };
 
+    let deserialize_impl = (impls != PolyImpls::SerializeOnly).then_some(deserialize_impl);
+    let serialize_impl = (impls != PolyImpls::DeserializeOnly).then_some(serialize_impl);
+    let pair_impl = (impls == PolyImpls::Both).then_some(pair_impl);
+
+    let round_trip_test = match poly_attrs.test {
+        Some(span) if impls != PolyImpls::Both => {
+            return Err(syn::Error::new(
+                span,
+                "poly(test) needs both poly impls, use #[derive(Poly)]",
+            ));
+        }
+        Some(span) => Some(round_trip_test(&krate, &ident, &generics, span)?),
+        None => None,
+    };
+
     Ok(quote! {
         #poly_items
+        #marker_fn
         #deserialize_impl
         #serialize_impl
+        #pair_impl
+        #marker_impl
+        #tag_impl
+        #version_impl
+        #ownable_impl
+        #nested_json_accessors
+        #round_trip_test
     })
 }
 
-struct PolyName {
-    ident: Ident,
-    attr_span: Option<Span>,
+/// Generate a `#[cfg(test)]` module that round-trips `Default::default()` through JSON: serialize,
+/// deserialize the borrowed `Out<'_>`, convert with `OwnablePoly::into_owned`, and compare. The
+/// module comes from `__poly_round_trip_test!`, which errors without the `serde_json` feature.
+fn round_trip_test(
+    krate: &syn::Path,
+    ident: &Ident,
+    generics: &syn::Generics,
+    span: Span,
+) -> syn::Result<TokenStream2> {
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return Err(syn::Error::new(
+            span,
+            "poly(test) builds the type with `Default::default()`, so it is only valid for types \
+             without type or const parameters",
+        ));
+    }
+
+    let lifetimes = generics.lifetimes().map(|_| quote!('static));
+    let ty = quote!(#ident < #(#lifetimes),* >);
+    let module = format_ident!("__{}_poly_test", ident);
+
+    Ok(quote! {
+        #krate::__poly_round_trip_test!(#module, #ty);
+    })
+}
+
+#[derive(Default)]
+struct PolyAttrs {
+    krate: Option<syn::Path>,
+    derives: Vec<syn::Path>,
+    derive_span: Option<Span>,
+    name: Option<Ident>,
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    force_marker: Option<Span>,
+    marker_fn: Option<Span>,
+    phantom_lifetime: Option<Span>,
+    lifetime: Option<Lifetime>,
+    test: Option<Span>,
+    vis: Option<Visibility>,
+    module: Option<Ident>,
+    doc: Option<LitStr>,
+    ownable: Option<Span>,
+    transparent: Option<Span>,
+    uninhabited: Option<Span>,
+    variance: Option<(Variance, Span)>,
+    tag: Option<LitStr>,
+    version: Option<syn::LitInt>,
+    upgrade_from: Option<Type>,
+    use_marker: Option<Type>,
+    serialize_bound: Option<Vec<WherePredicate>>,
+    deserialize_bound: Option<Vec<WherePredicate>>,
+    no_bound: Option<Span>,
+    allow_unborrowed: Option<Span>,
 }
 
-fn parse_poly_name(attrs: &[Attribute], original: &Ident) -> syn::Result<PolyName> {
-    let mut provided = None;
-    let mut span = None;
+fn parse_poly_attrs(attrs: &[Attribute]) -> syn::Result<PolyAttrs> {
+    let mut poly_attrs = PolyAttrs::default();
 
     for attr in attrs {
         if !attr.path().is_ident("poly") {
@@ -233,33 +1062,989 @@ fn parse_poly_name(attrs: &[Attribute], original: &Ident) -> syn::Result<PolyNam
             Meta::Path(_) => {}
             Meta::List(_) => {
                 attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("name") {
+                    if meta.path.is_ident("crate") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.krate = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("derive") {
+                        poly_attrs.derive_span = Some(meta.path.span());
+                        meta.parse_nested_meta(|derive| {
+                            poly_attrs.derives.push(derive.path);
+                            Ok(())
+                        })
+                    } else if meta.path.is_ident("name") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.name = Some(Ident::new(&lit.value(), lit.span()));
+                        Ok(())
+                    } else if meta.path.is_ident("prefix") {
+                        poly_attrs.prefix = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("suffix") {
+                        poly_attrs.suffix = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("force_marker") {
+                        poly_attrs.force_marker = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("marker_fn") {
+                        poly_attrs.marker_fn = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("phantom_lifetime") {
+                        poly_attrs.phantom_lifetime = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("lifetime") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.lifetime = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("vis") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.vis = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("module") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.module = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("doc") {
+                        poly_attrs.doc = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("ownable") {
+                        poly_attrs.ownable = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("transparent") {
+                        poly_attrs.transparent = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("uninhabited") {
+                        poly_attrs.uninhabited = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("variance") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        let variance = match lit.value().as_str() {
+                            "covariant" => Variance::Covariant,
+                            "contravariant" => Variance::Contravariant,
+                            "invariant" => Variance::Invariant,
+                            _ => {
+                                return Err(syn::Error::new(
+                                    lit.span(),
+                                    "expected \"covariant\", \"contravariant\" or \"invariant\"",
+                                ));
+                            }
+                        };
+                        poly_attrs.variance = Some((variance, lit.span()));
+                        Ok(())
+                    } else if meta.path.is_ident("tag") {
+                        poly_attrs.tag = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("version") {
+                        poly_attrs.version = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("upgrade_from") {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        poly_attrs.upgrade_from = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("use_marker") {
                         let lit: LitStr = meta.value()?.parse()?;
-                        let ident = Ident::new(&lit.value(), lit.span());
-                        provided = Some(ident);
-                        span = Some(lit.span());
+                        poly_attrs.use_marker = Some(lit.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("test") {
+                        poly_attrs.test = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("bound") {
+                        if meta.input.peek(Token![=]) {
+                            let predicates = parse_bound(&meta.value()?.parse()?)?;
+                            poly_attrs.serialize_bound = Some(predicates.clone());
+                            poly_attrs.deserialize_bound = Some(predicates);
+                            Ok(())
+                        } else {
+                            meta.parse_nested_meta(|bound| {
+                                if bound.path.is_ident("serialize") {
+                                    let lit = bound.value()?.parse()?;
+                                    poly_attrs.serialize_bound = Some(parse_bound(&lit)?);
+                                    Ok(())
+                                } else if bound.path.is_ident("deserialize") {
+                                    let lit = bound.value()?.parse()?;
+                                    poly_attrs.deserialize_bound = Some(parse_bound(&lit)?);
+                                    Ok(())
+                                } else {
+                                    Err(bound.error("expected `serialize` or `deserialize`"))
+                                }
+                            })
+                        }
+                    } else if meta.path.is_ident("no_bound") {
+                        poly_attrs.no_bound = Some(meta.path.span());
+                        Ok(())
+                    } else if meta.path.is_ident("allow_unborrowed") {
+                        poly_attrs.allow_unborrowed = Some(meta.path.span());
                         Ok(())
                     } else {
-                        Err(meta.error("unsupported poly attribute"))
+                        let key = meta.path.to_token_stream().to_string().replace(' ', "");
+                        Err(meta.error(format!(
+                            "unsupported poly attribute `{}`, expected one of: {}",
+                            key,
+                            POLY_ATTRIBUTES.join(", ")
+                        )))
                     }
                 })?;
             }
             Meta::NameValue(_) => {
+                return Err(syn::Error::new(attr.span(), "unsupported poly attribute"));
+            }
+        }
+    }
+
+    if let (Some(span), true) = (
+        poly_attrs.no_bound,
+        poly_attrs.serialize_bound.is_some() || poly_attrs.deserialize_bound.is_some(),
+    ) {
+        return Err(syn::Error::new(
+            span,
+            "poly(no_bound) can't be combined with poly(bound = \"...\")",
+        ));
+    }
+
+    if let (Some(name), Some(affix)) = (
+        &poly_attrs.name,
+        poly_attrs.prefix.as_ref().or(poly_attrs.suffix.as_ref()),
+    ) {
+        let mut error = syn::Error::new(
+            affix.span(),
+            "poly(prefix) and poly(suffix) build the Poly type's name, which poly(name) already \
+             gives in full",
+        );
+        error.combine(syn::Error::new(name.span(), "the name is given here"));
+        return Err(error);
+    }
+
+    if let Some(marker) = &poly_attrs.use_marker {
+        let conflicts = [
+            ("name", poly_attrs.name.as_ref().map(|name| name.span())),
+            (
+                "prefix",
+                poly_attrs.prefix.as_ref().map(|prefix| prefix.span()),
+            ),
+            (
+                "suffix",
+                poly_attrs.suffix.as_ref().map(|suffix| suffix.span()),
+            ),
+            ("vis", poly_attrs.vis.as_ref().map(|vis| vis.span())),
+            (
+                "module",
+                poly_attrs.module.as_ref().map(|module| module.span()),
+            ),
+            ("doc", poly_attrs.doc.as_ref().map(|doc| doc.span())),
+            ("derive", poly_attrs.derive_span),
+            ("force_marker", poly_attrs.force_marker),
+            ("marker_fn", poly_attrs.marker_fn),
+            ("phantom_lifetime", poly_attrs.phantom_lifetime),
+            ("transparent", poly_attrs.transparent),
+            ("uninhabited", poly_attrs.uninhabited),
+            ("variance", poly_attrs.variance.map(|(_, span)| span)),
+        ];
+        if let Some((key, span)) = conflicts
+            .into_iter()
+            .find_map(|(key, span)| span.map(|span| (key, span)))
+        {
+            let mut error = syn::Error::new(
+                span,
+                format!(
+                    "poly({}) configures a generated Poly type, but poly(use_marker) uses an \
+                     existing one",
+                    key
+                ),
+            );
+            error.combine(syn::Error::new(
+                marker.span(),
+                "the existing Poly type is given here",
+            ));
+            return Err(error);
+        }
+    }
+
+    if let (None, Some(upgrade_from)) = (&poly_attrs.version, &poly_attrs.upgrade_from) {
+        return Err(syn::Error::new(
+            upgrade_from.span(),
+            "poly(upgrade_from) needs a poly(version = N) to upgrade to",
+        ));
+    }
+
+    Ok(poly_attrs)
+}
+
+/// The impls for `#[poly(transparent)]`, which delegate to the single field's type: `SerializePoly`
+/// reuses its `Out`, and types without lifetimes deserialize to its `Out<'de>` too. No Poly type is
+/// generated, as the field type's serves instead.
+fn expand_transparent(
+    poly_attrs: &PolyAttrs,
+    krate: &syn::Path,
+    attrs: &[Attribute],
+    ident: &Ident,
+    generics: &syn::Generics,
+    data: &Data,
+    impls: PolyImpls,
+) -> syn::Result<TokenStream2> {
+    let span = poly_attrs.transparent.unwrap_or_else(Span::call_site);
+    let marker_attrs = [
+        poly_attrs.name.as_ref().map(|name| name.span()),
+        poly_attrs.prefix.as_ref().map(|prefix| prefix.span()),
+        poly_attrs.suffix.as_ref().map(|suffix| suffix.span()),
+        poly_attrs.force_marker,
+        poly_attrs.marker_fn,
+        poly_attrs.uninhabited,
+        poly_attrs.variance.map(|(_, span)| span),
+        poly_attrs.derive_span,
+        poly_attrs.module.as_ref().map(|module| module.span()),
+        poly_attrs.vis.as_ref().map(|_| span),
+        poly_attrs.doc.as_ref().map(|doc| doc.span()),
+        poly_attrs.test,
+        poly_attrs.phantom_lifetime,
+        poly_attrs.lifetime.as_ref().map(|lifetime| lifetime.span()),
+        poly_attrs.serialize_bound.as_ref().map(|_| span),
+        poly_attrs.deserialize_bound.as_ref().map(|_| span),
+        poly_attrs.no_bound,
+        poly_attrs.allow_unborrowed,
+    ];
+    if let Some(attr_span) = marker_attrs.into_iter().flatten().next() {
+        return Err(syn::Error::new(
+            attr_span,
+            "poly(transparent) reuses the field type's Poly type, so it can't be configured here",
+        ));
+    }
+
+    let is_serde_transparent = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("transparent"));
+    if !is_serde_transparent {
+        return Err(syn::Error::new(
+            span,
+            "poly(transparent) needs #[serde(transparent)], so the type serializes exactly as its \
+             field does",
+        ));
+    }
+
+    let field_ty = match data {
+        Data::Struct(data) => {
+            let mut fields = data
+                .fields
+                .iter()
+                .filter(|field| !is_phantom_data(&field.ty));
+            match (fields.next(), fields.next()) {
+                (Some(field), None) => &field.ty,
+                _ => {
+                    return Err(syn::Error::new(
+                        span,
+                        "poly(transparent) needs a struct with exactly one non-PhantomData field",
+                    ));
+                }
+            }
+        }
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                "poly(transparent) is only valid for structs",
+            ));
+        }
+    };
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let mut serialize_generics = generics.clone();
+    serialize_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_ty: #krate::SerializePoly));
+    let (_, _, serialize_where_clause) = serialize_generics.split_for_impl();
+    let serialize_impl = (impls != PolyImpls::DeserializeOnly).then(|| {
+        quote! {
+            impl #impl_generics #krate::SerializePoly for #ident #ty_generics #serialize_where_clause {
+                type Out = <#field_ty as #krate::SerializePoly>::Out;
+            }
+        }
+    });
+
+    // With a lifetime, the field type's Poly type already implements `DeserializePoly`.
+    let has_lifetime = generics.lifetimes().next().is_some();
+    let deserialize_impl = (impls != PolyImpls::SerializeOnly && !has_lifetime).then(|| {
+        let mut deserialize_generics = generics.clone();
+        deserialize_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_ty: #krate::DeserializePoly));
+        let (_, _, deserialize_where_clause) = deserialize_generics.split_for_impl();
+        quote! {
+            impl #impl_generics #krate::DeserializePoly for #ident #ty_generics #deserialize_where_clause {
+                type Out<'de> = <#field_ty as #krate::DeserializePoly>::Out<'de>;
+            }
+        }
+    });
+
+    let pair_impl = (impls == PolyImpls::Both).then(|| {
+        let mut pair_generics = generics.clone();
+        pair_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#field_ty: #krate::PolyPair));
+        let (_, _, pair_where_clause) = pair_generics.split_for_impl();
+        quote! {
+            impl #impl_generics #krate::PolyPair for #ident #ty_generics #pair_where_clause {
+                type Marker = <#field_ty as #krate::PolyPair>::Marker;
+            }
+        }
+    });
+
+    Ok(quote! {
+        #serialize_impl
+        #deserialize_impl
+        #pair_impl
+    })
+}
+
+/// The impls for `#[poly(version = N)]`. `DeserializeVersion` is implemented for every lifetime of
+/// the type that serde's `Deserialize` is, and deserializes older versions as the `upgrade_from`
+/// type before converting them with `Upgrade`.
+fn expand_version(
+    krate: &syn::Path,
+    ident: &Ident,
+    generics: &syn::Generics,
+    version: &syn::LitInt,
+    upgrade_from: Option<&Type>,
+) -> syn::Result<TokenStream2> {
+    let version = version.base10_parse::<u32>()?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let predicates = &mut de_generics.make_where_clause().predicates;
+    predicates.push(syn::parse_quote!(Self: #krate::__private::serde::Deserialize<'de>));
+    let older = match upgrade_from {
+        Some(previous) => {
+            predicates.push(syn::parse_quote!(#previous: #krate::DeserializeVersion<'de>));
+            predicates.push(syn::parse_quote!(Self: #krate::Upgrade<#previous>));
+            quote! {
+                let previous = <#previous as #krate::DeserializeVersion<'de>>::deserialize_version(
+                    version,
+                    deserializer,
+                )?;
+                Ok(<Self as #krate::Upgrade<#previous>>::upgrade(previous))
+            }
+        }
+        None => quote! {
+            Err(<__D::Error as #krate::__private::serde::de::Error>::custom(::core::format_args!(
+                "unsupported version {} of `{}`, expected {}",
+                version,
+                ::core::stringify!(#ident),
+                #version,
+            )))
+        },
+    };
+    let (de_impl_generics, _, de_where_clause) = de_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::PolyVersion for #ident #ty_generics #where_clause {
+            const VERSION: u32 = #version;
+        }
+
+        impl #de_impl_generics #krate::DeserializeVersion<'de> for #ident #ty_generics #de_where_clause {
+            fn deserialize_version<__D>(version: u32, deserializer: __D) -> Result<Self, __D::Error>
+            where
+                __D: #krate::__private::serde::Deserializer<'de>,
+            {
+                if version == #version {
+                    <Self as #krate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                } else if version < #version {
+                    #older
+                } else {
+                    Err(<__D::Error as #krate::__private::serde::de::Error>::custom(::core::format_args!(
+                        "unsupported version {} of `{}`, expected at most {}",
+                        version,
+                        ::core::stringify!(#ident),
+                        #version,
+                    )))
+                }
+            }
+        }
+    })
+}
+
+/// The lifetime parameters bounded by `'static`, inline as `'a: 'static` or in the where clause.
+fn static_lifetimes(generics: &syn::Generics) -> Vec<Ident> {
+    let is_static = |bounds: &Punctuated<Lifetime, Token![+]>| {
+        bounds.iter().any(|bound| bound.ident == "static")
+    };
+    let inline = generics
+        .lifetimes()
+        .filter(|param| is_static(&param.bounds))
+        .map(|param| param.lifetime.ident.clone());
+    let where_clause = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .filter_map(|predicate| match predicate {
+            WherePredicate::Lifetime(predicate) if is_static(&predicate.bounds) => {
+                Some(predicate.lifetime.ident.clone())
+            }
+            _ => None,
+        });
+    inline.chain(where_clause).collect()
+}
+
+/// One error on each lifetime that competes for the deserializer's, naming the first lifetime, and
+/// a hint on the first suggesting `#[poly(lifetime)]` with the actual lifetime names.
+fn multiple_lifetimes_error(lifetimes: &[Lifetime]) -> syn::Error {
+    let first = &lifetimes[0];
+    let mut error = syn::Error::new(
+        first.span(),
+        format!(
+            "Poly derive can only bind one lifetime to the deserializer, add \
+             #[poly(lifetime = \"{}\")] to choose it, and the others are fixed to 'static",
+            first
+        ),
+    );
+    for lifetime in &lifetimes[1..] {
+        error.combine(syn::Error::new(
+            lifetime.span(),
+            format!(
+                "`{}` is another lifetime besides `{}`, use #[poly(lifetime = \"{}\")] if it \
+                 borrows from the deserializer instead",
+                lifetime, first, lifetime
+            ),
+        ));
+    }
+    error
+}
+
+/// The `(serialize, deserialize)` predicates of serde's `bound` attribute.
+type SerdeBounds = (Option<Vec<WherePredicate>>, Option<Vec<WherePredicate>>);
+
+/// Read `#[serde(bound = "...")]` or `#[serde(bound(serialize = "...", deserialize = "..."))]`.
+/// Malformed serde attributes are left for serde's own derive to report.
+fn serde_bounds(attrs: &[Attribute]) -> SerdeBounds {
+    let mut bounds: SerdeBounds = (None, None);
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                if meta.input.peek(Token![=]) {
+                    let predicates = parse_bound(&meta.value()?.parse()?)?;
+                    bounds = (Some(predicates.clone()), Some(predicates));
+                    Ok(())
+                } else {
+                    meta.parse_nested_meta(|bound| {
+                        let predicates = parse_bound(&bound.value()?.parse()?)?;
+                        if bound.path.is_ident("serialize") {
+                            bounds.0 = Some(predicates);
+                        } else if bound.path.is_ident("deserialize") {
+                            bounds.1 = Some(predicates);
+                        }
+                        Ok(())
+                    })
+                }
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<syn::Expr>().map(drop)
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<TokenTree>().map(drop)
+            } else {
+                Ok(())
+            }
+        });
+    }
+    bounds
+}
+
+/// Make a predicate that mentions serde's `'de` lifetime higher-ranked over it.
+fn higher_ranked_de(mut predicate: WherePredicate) -> WherePredicate {
+    let WherePredicate::Type(predicate_type) = &mut predicate else {
+        return predicate;
+    };
+    let is_de = |lifetime: &Lifetime| lifetime.ident == "de";
+    if predicate_type.lifetimes.iter().any(|binder| {
+        binder.lifetimes.iter().any(
+            |param| matches!(param, GenericParam::Lifetime(lifetime) if is_de(&lifetime.lifetime)),
+        )
+    }) {
+        return predicate;
+    }
+
+    let mut mentions_de =
+        MapLifetimes::new(|lifetime: &Lifetime| is_de(lifetime).then(|| lifetime.clone()));
+    mentions_de.visit_type_mut(&mut predicate_type.bounded_ty);
+    for bound in &mut predicate_type.bounds {
+        let bound_binds_de = matches!(bound, TypeParamBound::Trait(trait_bound)
+        if trait_bound.lifetimes.iter().any(|binder| binder.lifetimes.iter().any(|param| {
+            matches!(param, GenericParam::Lifetime(lifetime) if is_de(&lifetime.lifetime))
+        })));
+        if !bound_binds_de {
+            mentions_de.visit_type_param_bound_mut(bound);
+        }
+    }
+    if mentions_de.replaced {
+        predicate_type
+            .lifetimes
+            .get_or_insert_with(Default::default)
+            .lifetimes
+            .push(syn::parse_quote!('de));
+    }
+    predicate
+}
+
+/// Parse the where-predicates of a `bound = "..."` attribute, an empty string meaning none.
+pub(crate) fn parse_bound(lit: &LitStr) -> syn::Result<Vec<WherePredicate>> {
+    let predicates = lit.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+    Ok(predicates.into_iter().collect())
+}
+
+/// Rewrite a bound from the source type for the marker, which has no lifetime parameters. Trait
+/// bounds on the deserializer lifetime become higher-ranked over `'de` (unless the enclosing
+/// predicate already is), and outlives bounds and any other lifetimes become `'static`.
+fn marker_bound(
+    bound: &TypeParamBound,
+    de_lifetime: Option<&Lifetime>,
+    lifetimes: &[Lifetime],
+    higher_ranked: bool,
+) -> TypeParamBound {
+    let mut bound = bound.clone();
+    if let TypeParamBound::Trait(trait_bound) = &mut bound {
+        let mut to_de = MapLifetimes::new(|lifetime: &Lifetime| {
+            (Some(lifetime) == de_lifetime).then(|| Lifetime::new("'de", lifetime.span()))
+        });
+        to_de.visit_trait_bound_mut(trait_bound);
+        if to_de.replaced && higher_ranked {
+            trait_bound
+                .lifetimes
+                .get_or_insert_with(Default::default)
+                .lifetimes
+                .push(syn::parse_quote!('de));
+        }
+    }
+
+    MapLifetimes::new(|lifetime: &Lifetime| {
+        lifetimes
+            .contains(lifetime)
+            .then(|| Lifetime::new("'static", lifetime.span()))
+    })
+    .visit_type_param_bound_mut(&mut bound);
+    bound
+}
+
+/// Rewrite a where-clause predicate from the source type for the marker, see [`marker_bound`].
+/// Lifetime predicates such as `'a: 'b` have no meaning without the lifetimes and are dropped.
+fn marker_predicate(
+    predicate: &WherePredicate,
+    de_lifetime: Option<&Lifetime>,
+    lifetimes: &[Lifetime],
+) -> Option<WherePredicate> {
+    let WherePredicate::Type(predicate) = predicate else {
+        return None;
+    };
+
+    let mut predicate = predicate.clone();
+    let mut to_de = MapLifetimes::new(|lifetime: &Lifetime| {
+        (Some(lifetime) == de_lifetime).then(|| Lifetime::new("'de", lifetime.span()))
+    });
+    to_de.visit_type_mut(&mut predicate.bounded_ty);
+    let higher_ranked = to_de.replaced;
+    if higher_ranked {
+        predicate
+            .lifetimes
+            .get_or_insert_with(Default::default)
+            .lifetimes
+            .push(syn::parse_quote!('de));
+    }
+
+    MapLifetimes::new(|lifetime: &Lifetime| {
+        lifetimes
+            .contains(lifetime)
+            .then(|| Lifetime::new("'static", lifetime.span()))
+    })
+    .visit_type_mut(&mut predicate.bounded_ty);
+    predicate.bounds = predicate
+        .bounds
+        .iter()
+        .map(|bound| marker_bound(bound, de_lifetime, lifetimes, !higher_ranked))
+        .collect();
+    Some(WherePredicate::Type(predicate))
+}
+
+/// Give every token the same span, so errors about the tokens point at it.
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
+
+/// All fields of a struct, or of every variant of an enum.
+fn fields(data: &Data) -> Box<dyn Iterator<Item = &Field> + '_> {
+    match data {
+        Data::Struct(data_struct) => Box::new(data_struct.fields.iter()),
+        Data::Enum(data_enum) => Box::new(
+            data_enum
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter()),
+        ),
+        Data::Union(data_union) => Box::new(data_union.fields.named.iter()),
+    }
+}
+
+/// Every field, with the enum variant holding it.
+fn variant_fields(data: &Data) -> Vec<(Option<&syn::Variant>, &Field)> {
+    match data {
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .map(move |field| (Some(variant), field))
+            })
+            .collect(),
+        _ => fields(data).map(|field| (None, field)).collect(),
+    }
+}
+
+/// Generate a getter and setter on the type for each `#[poly(nested_json)]` field, which must be a
+/// `NestedJson<'a, T>` with the getter returning `T::Out<'_>`.
+fn nested_json_accessors(
+    krate: &syn::Path,
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &syn::Generics,
+    data: &Data,
+    impls: PolyImpls,
+    remote: bool,
+) -> syn::Result<Option<TokenStream2>> {
+    let mut accessors = Vec::new();
+    for (variant, field) in variant_fields(data) {
+        let Some(span) = parse_poly_field_attrs(&field.attrs)? else {
+            continue;
+        };
+        let field_ident = match (variant, &field.ident) {
+            (None, Some(field_ident)) if !remote => field_ident,
+            _ => {
                 return Err(syn::Error::new(
-                    attr.span(),
-                    "unsupported poly attribute",
+                    span,
+                    "poly(nested_json) adds accessors to the type, so needs a named field of a \
+                     local struct",
                 ));
             }
+        };
+        let Some(value_poly) = nested_json_value(&field.ty) else {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "poly(nested_json) needs a `NestedJson<'a, T>` field, with `T` the nested value's \
+                 Poly type",
+            ));
+        };
+        if impls != PolyImpls::SerializeOnly
+            && !has_serde_attr(&field.attrs, &["borrow"])
+            && !is_skipped_deserializing(variant, field)
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "this `NestedJson` needs #[serde(borrow)] to borrow from the parent document",
+            ));
         }
+
+        let getter_doc = format!("Deserializes the nested JSON in `{}`.", field_ident);
+        let setter = format_ident!("set_{}", field_ident);
+        let setter_doc = format!("Serializes `value` into `{}` as nested JSON.", field_ident);
+        accessors.push(quote! {
+            #[doc = #getter_doc]
+            #vis fn #field_ident(
+                &self,
+            ) -> ::core::result::Result<
+                <#value_poly as #krate::DeserializePoly>::Out<'_>,
+                #krate::__private::serde_json::Error,
+            >
+            where
+                #value_poly: #krate::DeserializePoly,
+            {
+                self.#field_ident.get()
+            }
+
+            #[doc = #setter_doc]
+            #vis fn #setter<__V>(
+                &mut self,
+                value: &__V,
+            ) -> ::core::result::Result<(), #krate::__private::serde_json::Error>
+            where
+                __V: #krate::SerializePoly<Out = #value_poly> + ?::core::marker::Sized,
+            {
+                self.#field_ident.set(value)
+            }
+        });
     }
 
-    let ident = match provided {
-        Some(ident) => ident,
-        None => format_ident!("{}Poly", original),
+    if accessors.is_empty() {
+        return Ok(None);
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(Some(quote! {
+        #[allow(dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#accessors)*
+        }
+    }))
+}
+
+/// Read a field's `#[poly(...)]` attributes, returning the span of `nested_json` if given.
+fn parse_poly_field_attrs(attrs: &[Attribute]) -> syn::Result<Option<Span>> {
+    let mut nested_json = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("poly")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested_json") {
+                nested_json = Some(meta.path.span());
+                Ok(())
+            } else {
+                let key = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format!(
+                    "unsupported poly field attribute `{}`, expected nested_json",
+                    key
+                )))
+            }
+        })?;
+    }
+    Ok(nested_json)
+}
+
+/// The `T` of a `NestedJson<'a, T>` type.
+fn nested_json_value(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
     };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "NestedJson" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
 
-    Ok(PolyName {
-        ident,
-        attr_span: span,
+/// Name the generated Poly type from the source type's name, with the `prefix` and `suffix`
+/// attributes replacing the default empty prefix and `Poly` suffix.
+fn poly_name(
+    ident: &Ident,
+    prefix: Option<&LitStr>,
+    suffix: Option<&LitStr>,
+) -> syn::Result<Ident> {
+    let (span, source) = match (prefix, suffix) {
+        (Some(prefix), _) => (prefix.span(), "poly(prefix)"),
+        (None, Some(suffix)) => (suffix.span(), "poly(suffix)"),
+        (None, None) => return Ok(format_ident!("{}Poly", ident)),
+    };
+    let name = format!(
+        "{}{}{}",
+        prefix.map_or_else(String::new, LitStr::value),
+        ident.unraw(),
+        suffix.map_or_else(|| "Poly".to_string(), LitStr::value),
+    );
+    if name == ident.unraw().to_string() {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "the Poly type name `{}` from {} is the same as the source type's",
+                name, source
+            ),
+        ));
+    }
+    syn::parse_str::<Ident>(&name)
+        .map(|poly_ident| Ident::new(&poly_ident.to_string(), ident.span()))
+        .map_err(|_| {
+            syn::Error::new(
+                span,
+                format!(
+                    "the Poly type name `{}` from {} is not a valid identifier",
+                    name, source
+                ),
+            )
+        })
+}
+
+/// Check if any field holds data borrowed for `lifetime`, ignoring `PhantomData` fields.
+fn lifetime_is_used(data: &Data, lifetime: &Lifetime) -> bool {
+    let lifetimes = std::slice::from_ref(lifetime);
+    fields(data).any(|field| {
+        !is_phantom_data(&field.ty) && type_contains_any_lifetime(&field.ty, lifetimes)
     })
 }
+
+/// serde borrows `&'a str` and `&'a [u8]` fields implicitly, but a `Cow<'a, str>` or
+/// `Cow<'a, [u8]>` field always deserializes owned data without `#[serde(borrow)]`, silently
+/// defeating zero-copy deserialization. Other borrowing fields fail to compile in serde's derive
+/// without it, so aren't checked here.
+fn unborrowed_cow_check(data: &Data, lifetime: &Lifetime) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    for (variant, field) in variant_fields(data) {
+        // serde also accepts `#[serde(borrow)]` on a newtype variant.
+        let variant_borrows = variant.is_some_and(|variant| {
+            variant.fields.len() == 1 && has_serde_attr(&variant.attrs, &["borrow"])
+        });
+        if variant_borrows
+            || !is_borrowable_cow(&field.ty, lifetime)
+            || has_serde_attr(&field.attrs, &["borrow"])
+            || is_skipped_deserializing(variant, field)
+        {
+            continue;
+        }
+        let error = syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "this `Cow` always deserializes owned data, add #[serde(borrow)] to borrow \
+                 from the deserializer for `{}`, or #[poly(allow_unborrowed)] to the type to \
+                 allow it",
+                lifetime
+            ),
+        );
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Lifetimes other than the deserializer's are fixed to `'static` in `Out<'de>`, so a deserialized
+/// field borrowing one would need `'de: 'static` and fail with an error about the whole type's
+/// `Deserialize` impl. Report it on the field, naming the variant of an enum, instead.
+fn fixed_lifetime_check(
+    data: &Data,
+    fixed: &[Lifetime],
+    de_lifetime: &Lifetime,
+) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    for (variant, field) in variant_fields(data) {
+        let Some(lifetime) = fixed
+            .iter()
+            .find(|lifetime| type_borrows_lifetime(&field.ty, lifetime))
+        else {
+            continue;
+        };
+        if is_phantom_data(&field.ty) || is_skipped_deserializing(variant, field) {
+            continue;
+        }
+        let location = match variant {
+            Some(variant) => format!("variant `{}` borrows", variant.ident),
+            None => "this field borrows".to_string(),
+        };
+        let error = syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "{} `{}` from the deserializer, but only `{}` is bound to it and `{}` is fixed \
+                 to 'static, skip deserializing the field or choose `{}` with \
+                 #[poly(lifetime = \"{}\")]",
+                location, lifetime, de_lifetime, lifetime, lifetime, lifetime
+            ),
+        );
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Check if a type is a `&'lifetime` reference, which serde deserializes by borrowing, or one
+/// nested in a tuple, array or slice.
+fn type_borrows_lifetime(ty: &Type, lifetime: &Lifetime) -> bool {
+    match ty {
+        Type::Reference(reference) => reference
+            .lifetime
+            .as_ref()
+            .is_some_and(|lt| lt.ident == lifetime.ident),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .any(|elem| type_borrows_lifetime(elem, lifetime)),
+        Type::Array(array) => type_borrows_lifetime(&array.elem, lifetime),
+        Type::Paren(paren) => type_borrows_lifetime(&paren.elem, lifetime),
+        Type::Group(group) => type_borrows_lifetime(&group.elem, lifetime),
+        _ => false,
+    }
+}
+
+/// Check if serde never deserializes a field, by its own or its variant's attributes.
+fn is_skipped_deserializing(variant: Option<&syn::Variant>, field: &Field) -> bool {
+    let names = &["skip", "skip_deserializing", "with", "deserialize_with"];
+    has_serde_attr(&field.attrs, names)
+        || variant.is_some_and(|variant| has_serde_attr(&variant.attrs, names))
+}
+
+/// Check if a type is `Cow<'lifetime, str>` or `Cow<'lifetime, [u8]>`, the types serde borrows.
+fn is_borrowable_cow(ty: &Type, lifetime: &Lifetime) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return false;
+    };
+    let mut args = arguments.args.iter();
+    segment.ident == "Cow"
+        && matches!(args.next(), Some(syn::GenericArgument::Lifetime(lt)) if lt.ident == lifetime.ident)
+        && match args.next() {
+            Some(syn::GenericArgument::Type(Type::Path(inner))) => inner.path.is_ident("str"),
+            Some(syn::GenericArgument::Type(Type::Slice(slice))) => {
+                matches!(&*slice.elem, Type::Path(elem) if elem.path.is_ident("u8"))
+            }
+            _ => false,
+        }
+}
+
+/// Check if a field or variant has any of the named `#[serde(...)]` attributes. Malformed serde
+/// attributes are left for serde's own derive to report.
+fn has_serde_attr(attrs: &[Attribute], names: &[&str]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| names.iter().any(|name| meta.path().is_ident(name)))
+}
+
+/// Check if a type mentions any of the given identifiers, e.g. generic type parameters.
+pub(crate) fn type_mentions_any(ty: &Type, idents: &[Ident]) -> bool {
+    fn mentions(tokens: TokenStream2, idents: &[Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => idents.contains(&ident),
+            TokenTree::Group(group) => mentions(group.stream(), idents),
+            _ => false,
+        })
+    }
+
+    mentions(ty.to_token_stream(), idents)
+}
+
+pub(crate) fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
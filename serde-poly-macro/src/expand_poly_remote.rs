@@ -0,0 +1,123 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    Attribute, Data, DataStruct, DeriveInput, Fields, GenericArgument, GenericParam, Generics,
+    Ident, LifetimeParam, LitStr, Path, PathArguments, Token, Type, TypeParam, Visibility,
+    WhereClause,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use crate::expand_poly::{PolyImpls, expand_poly};
+
+/// `#[attrs] vis struct NamePoly for path::Remote<'a, T> where ...;`
+pub struct PolyRemote {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    struct_token: Token![struct],
+    name: Ident,
+    remote: Path,
+    where_clause: Option<WhereClause>,
+}
+
+impl Parse for PolyRemote {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let struct_token = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![for]>()?;
+        let remote = input.parse()?;
+        let where_clause = input.parse()?;
+        input.parse::<Option<Token![;]>>()?;
+        Ok(PolyRemote {
+            attrs,
+            vis,
+            struct_token,
+            name,
+            remote,
+            where_clause,
+        })
+    }
+}
+
+/// Declare the remote type's generics as a fieldless struct and expand the derive for it, with
+/// `Out<'de>` naming the remote type.
+pub fn expand_poly_remote(input: PolyRemote) -> syn::Result<TokenStream2> {
+    let PolyRemote {
+        mut attrs,
+        vis,
+        struct_token,
+        name,
+        mut remote,
+        where_clause,
+    } = input;
+
+    let span = remote.span();
+    let last = remote
+        .segments
+        .last_mut()
+        .ok_or_else(|| syn::Error::new(span, "expected the remote type's path"))?;
+    let ident = last.ident.clone();
+    let arguments = std::mem::replace(&mut last.arguments, PathArguments::None);
+
+    let mut params = Punctuated::<GenericParam, Token![,]>::new();
+    if let PathArguments::AngleBracketed(arguments) = arguments {
+        for argument in arguments.args {
+            let param = match argument {
+                GenericArgument::Lifetime(lifetime) => {
+                    GenericParam::Lifetime(LifetimeParam::new(lifetime))
+                }
+                GenericArgument::Type(Type::Path(ty))
+                    if ty.qself.is_none() && ty.path.get_ident().is_some() =>
+                {
+                    GenericParam::Type(TypeParam::from(ty.path.segments[0].ident.clone()))
+                }
+                argument => {
+                    return Err(syn::Error::new(
+                        argument.span(),
+                        "expected a lifetime or type parameter of the remote type",
+                    ));
+                }
+            };
+            params.push(param);
+        }
+    } else if !matches!(arguments, PathArguments::None) {
+        return Err(syn::Error::new(
+            remote.span(),
+            "expected the remote type's generics in angle brackets",
+        ));
+    }
+
+    if !params
+        .iter()
+        .any(|param| matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new(
+            remote.span(),
+            "poly_remote! is for types with a lifetime parameter, the orphan rule prevents \
+             implementing the poly traits for owned types from other crates",
+        ));
+    }
+
+    let poly_name = LitStr::new(&name.to_string(), name.span());
+    attrs.push(syn::parse_quote!(#[poly(name = #poly_name)]));
+
+    let input = DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics: Generics {
+            lt_token: Some(Default::default()),
+            params,
+            gt_token: Some(Default::default()),
+            where_clause,
+        },
+        data: Data::Struct(DataStruct {
+            struct_token,
+            fields: Fields::Unit,
+            semi_token: None,
+        }),
+    };
+    expand_poly(input, PolyImpls::DeserializeOnly, Some(remote))
+}
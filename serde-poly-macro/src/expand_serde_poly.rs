@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::{DeriveInput, Meta, Token, punctuated::Punctuated, spanned::Spanned};
+
+use crate::expand_ownable_poly::parse_crate_path;
+
+/// Add the serde, [`Poly`](crate::Poly) and [`OwnablePoly`](crate::OwnablePoly) derives to the
+/// item, leaving its `#[serde(...)]` and `#[poly(...)]` attributes for those derives to read.
+/// serde is reached through the crate path, so it needn't be a direct dependency.
+pub fn expand_serde_poly(args: TokenStream2, input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(syn::Error::new(
+            args.span(),
+            "#[serde_poly] takes no arguments, configure the derives with #[serde(...)] and \
+             #[poly(...)]",
+        ));
+    }
+
+    let krate = parse_crate_path(&input.attrs)?;
+    let has_serde_crate = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("crate"));
+    let serde_crate = (!has_serde_crate).then(|| {
+        let path = format!("{}::__private::serde", krate.to_token_stream());
+        quote!(#[serde(crate = #path)])
+    });
+    Ok(quote! {
+        #[derive(
+            #krate::__private::serde::Serialize,
+            #krate::__private::serde::Deserialize,
+            #krate::Poly,
+            #krate::OwnablePoly,
+        )]
+        #serde_crate
+        #input
+    })
+}
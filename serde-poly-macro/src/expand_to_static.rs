@@ -0,0 +1,349 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, GenericArgument, GenericParam, Ident, Lifetime, LitStr,
+    PathArguments, Token, Type, TypeParamBound, TypeReference, WherePredicate,
+    punctuated::Punctuated, visit_mut::VisitMut,
+};
+
+use crate::expand_ownable_poly::parse_crate_path;
+use crate::lifetimes::{MapLifetimes, type_contains_any_lifetime};
+
+pub fn expand_to_static(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    let krate = parse_crate_path(&attrs)?;
+    let to_static_attrs = parse_to_static_attrs(&attrs)?;
+
+    let fields = match data {
+        Data::Struct(data_struct) => data_struct.fields,
+        Data::Enum(data_enum) => {
+            return Err(syn::Error::new_spanned(
+                data_enum.enum_token,
+                "ToStatic derive only supports structs",
+            ));
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "ToStatic derive only supports structs",
+            ));
+        }
+    };
+
+    let lifetime_params: Vec<Lifetime> =
+        generics.lifetimes().map(|lt| lt.lifetime.clone()).collect();
+    if lifetime_params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ident,
+            "ToStatic needs a type with lifetimes, an owned type is already its own owned form",
+        ));
+    }
+
+    let owned_ident = match &to_static_attrs.name {
+        Some(name) => name.parse::<Ident>()?,
+        None => format_ident!("{}Owned", ident),
+    };
+
+    // The owned type keeps the type's other generics, without its lifetimes. Bounds such as
+    // `T: 'a` go with them, rather than becoming `T: 'static`, as the owned type borrows nothing.
+    let mut owned_generics = generics.clone();
+    owned_generics.params = generics
+        .params
+        .iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+        .cloned()
+        .collect();
+    for param in owned_generics.type_params_mut() {
+        param.bounds = without_outlives(&param.bounds, &lifetime_params);
+        if param.bounds.is_empty() {
+            param.colon_token = None;
+        }
+    }
+    if let Some(where_clause) = &mut owned_generics.where_clause {
+        let mut to_static = static_lifetimes(&lifetime_params);
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter_map(|predicate| match predicate {
+                WherePredicate::Lifetime(_) => None,
+                WherePredicate::Type(predicate_type) => {
+                    let mut predicate_type = predicate_type.clone();
+                    predicate_type.bounds =
+                        without_outlives(&predicate_type.bounds, &lifetime_params);
+                    to_static.visit_predicate_type_mut(&mut predicate_type);
+                    (!predicate_type.bounds.is_empty())
+                        .then_some(WherePredicate::Type(predicate_type))
+                }
+                predicate => Some(predicate.clone()),
+            })
+            .collect();
+        if where_clause.predicates.is_empty() {
+            owned_generics.where_clause = None;
+        }
+    }
+
+    let mut owned_fields = Vec::new();
+    let mut from_fields = Vec::new();
+    let mut borrow_fields = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(i);
+                quote!(#index)
+            }
+        };
+        let (owned_ty, from, borrow) = owned_field(&krate, &field.ty, &lifetime_params, &member);
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let field_vis = &field.vis;
+        owned_fields.push(match &field.ident {
+            Some(ident) => quote!(#(#docs)* #field_vis #ident: #owned_ty),
+            None => quote!(#(#docs)* #field_vis #owned_ty),
+        });
+        from_fields.push(quote!(#member: #from));
+        borrow_fields.push(quote!(#member: #borrow));
+    }
+    let owned_body = match &fields {
+        Fields::Named(_) => quote!({ #(#owned_fields),* }),
+        Fields::Unnamed(_) => {
+            let where_clause = &owned_generics.where_clause;
+            quote!((#(#owned_fields),*) #where_clause;)
+        }
+        Fields::Unit => quote!(;),
+    };
+    let owned_where_clause = match &fields {
+        Fields::Named(_) => owned_generics.where_clause.as_ref(),
+        _ => None,
+    };
+
+    let derives = &to_static_attrs.derives;
+    let derive_attr = (!derives.is_empty()).then(|| quote!(#[derive(#(#derives),*)]));
+    let doc = format!(
+        "The owned form of [`{}`], with its borrowed fields replaced by owned ones.",
+        ident
+    );
+    let borrowed_doc = format!("Borrows the fields back as a [`{}`].", ident);
+
+    let owned_params = &owned_generics.params;
+    let (_, owned_ty_generics, _) = owned_generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (owned_impl_generics, _, owned_impl_where_clause) = owned_generics.split_for_impl();
+    let borrowed_args = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote!('_),
+        GenericParam::Type(ty) => ty.ident.to_token_stream(),
+        GenericParam::Const(konst) => konst.ident.to_token_stream(),
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #derive_attr
+        #vis struct #owned_ident <#owned_params> #owned_where_clause #owned_body
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #owned_ident #owned_ty_generics
+        #where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                #owned_ident {
+                    #(#from_fields),*
+                }
+            }
+        }
+
+        impl #owned_impl_generics #owned_ident #owned_ty_generics #owned_impl_where_clause {
+            #[doc = #borrowed_doc]
+            #[allow(clippy::clone_on_copy)]
+            #vis fn as_borrowed(&self) -> #ident<#(#borrowed_args),*> {
+                #ident {
+                    #(#borrow_fields),*
+                }
+            }
+        }
+    })
+}
+
+/// The owned type of a field, the expression converting it from `value`, and the expression
+/// borrowing it back from `self`.
+///
+/// References become their `ToOwned` type, also within an `Option` or `Vec`. Other fields
+/// borrowing the type's lifetimes are converted with `OwnablePoly`, and the rest are moved; both
+/// are cloned to borrow them back.
+fn owned_field(
+    krate: &syn::Path,
+    ty: &Type,
+    lifetime_params: &[Lifetime],
+    member: &TokenStream2,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    if let Some(type_ref) = borrowed_reference(ty, lifetime_params) {
+        return (
+            owned_reference(type_ref),
+            quote!(::std::borrow::ToOwned::to_owned(value.#member)),
+            quote!(::core::borrow::Borrow::borrow(&self.#member)),
+        );
+    }
+    if let Some((container, type_ref)) = wrapped_reference(ty, lifetime_params) {
+        let owned = owned_reference(type_ref);
+        return match container {
+            Container::Option => (
+                quote!(::core::option::Option<#owned>),
+                quote!(value.#member.map(::std::borrow::ToOwned::to_owned)),
+                quote!(self.#member.as_ref().map(::core::borrow::Borrow::borrow)),
+            ),
+            Container::Vec => (
+                quote!(::std::vec::Vec<#owned>),
+                quote!(value.#member.into_iter().map(::std::borrow::ToOwned::to_owned).collect()),
+                quote!(self.#member.iter().map(::core::borrow::Borrow::borrow).collect()),
+            ),
+        };
+    }
+    if type_contains_any_lifetime(ty, lifetime_params) {
+        let mut owned = ty.clone();
+        static_lifetimes(lifetime_params).visit_type_mut(&mut owned);
+        return (
+            owned.into_token_stream(),
+            quote!(#krate::OwnablePoly::into_owned(value.#member)),
+            quote!(::core::clone::Clone::clone(&self.#member)),
+        );
+    }
+    (
+        ty.to_token_stream(),
+        quote!(value.#member),
+        quote!(::core::clone::Clone::clone(&self.#member)),
+    )
+}
+
+/// The owned type of a reference's target, as named by `ToOwned`, with `str` and slices spelled
+/// out.
+fn owned_reference(type_ref: &TypeReference) -> TokenStream2 {
+    match &*type_ref.elem {
+        Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("str") => {
+            quote!(::std::string::String)
+        }
+        Type::Slice(type_slice) => {
+            let elem = &type_slice.elem;
+            quote!(::std::vec::Vec<#elem>)
+        }
+        elem => quote!(<#elem as ::std::borrow::ToOwned>::Owned),
+    }
+}
+
+/// A shared reference borrowing one of the type's lifetimes, whose target has no other borrows.
+fn borrowed_reference<'t>(ty: &'t Type, lifetime_params: &[Lifetime]) -> Option<&'t TypeReference> {
+    match ty {
+        Type::Reference(type_ref)
+            if type_ref.mutability.is_none()
+                && type_ref
+                    .lifetime
+                    .as_ref()
+                    .is_some_and(|lifetime| lifetime_params.contains(lifetime))
+                && !type_contains_any_lifetime(&type_ref.elem, lifetime_params) =>
+        {
+            Some(type_ref)
+        }
+        Type::Paren(type_paren) => borrowed_reference(&type_paren.elem, lifetime_params),
+        Type::Group(type_group) => borrowed_reference(&type_group.elem, lifetime_params),
+        _ => None,
+    }
+}
+
+enum Container {
+    Option,
+    Vec,
+}
+
+/// An `Option` or `Vec` of a borrowed reference.
+fn wrapped_reference<'t>(
+    ty: &'t Type,
+    lifetime_params: &[Lifetime],
+) -> Option<(Container, &'t TypeReference)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segment = type_path.path.segments.last()?;
+    let container = match segment.ident.to_string().as_str() {
+        "Option" => Container::Option,
+        "Vec" => Container::Vec,
+        _ => return None,
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>()[..] {
+        [GenericArgument::Type(inner)] => {
+            borrowed_reference(inner, lifetime_params).map(|type_ref| (container, type_ref))
+        }
+        _ => None,
+    }
+}
+
+/// The bounds without those outliving one of the type's lifetimes.
+fn without_outlives(
+    bounds: &Punctuated<TypeParamBound, Token![+]>,
+    lifetime_params: &[Lifetime],
+) -> Punctuated<TypeParamBound, Token![+]> {
+    bounds
+        .iter()
+        .filter(|bound| {
+            !matches!(bound, TypeParamBound::Lifetime(lifetime) if lifetime_params.contains(lifetime))
+        })
+        .cloned()
+        .collect()
+}
+
+fn static_lifetimes(
+    lifetime_params: &[Lifetime],
+) -> MapLifetimes<impl FnMut(&Lifetime) -> Option<Lifetime> + '_> {
+    MapLifetimes::new(|lifetime: &Lifetime| {
+        lifetime_params
+            .contains(lifetime)
+            .then(|| Lifetime::new("'static", lifetime.span()))
+    })
+}
+
+/// The type's `#[to_static(...)]` attributes.
+#[derive(Default)]
+struct ToStaticAttrs {
+    /// The owned type's name, instead of the type's name with an `Owned` suffix.
+    name: Option<LitStr>,
+    /// Derives for the owned type.
+    derives: Vec<syn::Path>,
+}
+
+fn parse_to_static_attrs(attrs: &[Attribute]) -> syn::Result<ToStaticAttrs> {
+    let mut to_static_attrs = ToStaticAttrs::default();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("to_static"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                to_static_attrs.name = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|derive| {
+                    to_static_attrs.derives.push(derive.path);
+                    Ok(())
+                })
+            } else {
+                let key = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format!(
+                    "unsupported to_static attribute `{}`, expected name or derive",
+                    key
+                )))
+            }
+        })?;
+    }
+    Ok(to_static_attrs)
+}
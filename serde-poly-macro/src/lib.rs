@@ -3,36 +3,114 @@
 //!   - impl [`DeserializePoly`] for `Self` with `type Out<'de> = Self`
 //!   - impl [`SerializePoly`] for `Self` with `type Out = Self`
 //! - For types with lifetimes, such as `MyType<'a, T>`:
-//!   - A tuple struct `MyTypePoly<T>(PhantomData<fn() -> T>)`, without lifetimes.
+//!   - A tuple struct `MyTypePoly<T>(PhantomData<fn() -> T>)`, without lifetimes, implementing
+//!     `Default`, `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` and
+//!     with a `const fn new()`.
 //!   - impl [`SerializePoly`] for `MyType<'a, T>` with `type Out = MyTypePoly<T>`
 //!   - impl [`DeserializePoly`] for `MyTypePoly<T>` with `type Out<'de> = MyType<'de, T>`
 //!
 //! Supports `#[poly(name = "CustomName")]` attributes to customize the name of the
-//! generated Poly type.
+//! generated Poly type, or `#[poly(prefix = "...", suffix = "...")]` to change what's added around
+//! the type's name.
 //!
-//! For types with multiple lifetime parameters, the derive macro fails with a clear
-//! error message.
+//! `#[poly(marker_fn)]` adds an inherent `const fn marker() -> MyTypePoly<T>` to `MyType<'a, T>`.
+//!
+//! `#[poly(uninhabited)]` generates the Poly type as an empty enum that can't be constructed, for
+//! use only in type position.
+//!
+//! `#[poly(variance = "contravariant")]` or `"invariant"` changes the Poly type's `PhantomData` to
+//! `fn(T)` or `fn(T) -> T`, from the covariant `fn() -> T`.
+//!
+//! `#[poly(nested_json)]` on a `NestedJson<'a, T>` field adds a getter that deserializes the
+//! nested JSON and a `set_` method that serializes a value into it.
+//!
+//! `#[poly(ownable)]` additionally implements `OwnablePoly`, as `#[derive(OwnablePoly)]` would.
+//!
+//! `#[poly(tag = "app.Event")]` additionally implements `PolyTag`, naming the type's tag in a
+//! `serde_poly::Tagged` envelope.
+//!
+//! `#[poly(version = 2, upgrade_from = "MyTypeV1<'a>")]` additionally implements `PolyVersion` and
+//! `DeserializeVersion`, reading older versions in a `serde_poly::Versioned` envelope as the
+//! previous type and converting them with `Upgrade`.
+//!
+//! `#[poly(test)]` additionally generates a `#[cfg(test)]` JSON round-trip test for the type, which
+//! needs serde-poly's `serde_json` feature.
+//!
+//! `Cow<'a, str>` and `Cow<'a, [u8]>` fields must have `#[serde(borrow)]`, as without it they never
+//! borrow from the deserializer; `#[poly(allow_unborrowed)]` allows them.
+//!
+//! Lifetimes that only appear in `PhantomData` fields are treated as owned types, unless
+//! `#[poly(force_marker)]` is given. `#[poly(phantom_lifetime)]` treats any type as owned.
+//!
+//! For types with multiple lifetime parameters, `#[poly(lifetime = "'a")]` chooses the lifetime
+//! bound to `'de`, and the others are fixed to `'static`. Without it, the derive macro fails with
+//! a clear error message.
+//!
+//! `#[ownable(clone)]` on a field of an `OwnablePoly` derive moves it through unchanged, for data
+//! that's already `'static`.
+//!
+//! `#[ownable(skip)]` or `#[ownable(skip = "expr")]` replaces a field that carries no data with
+//! `Default::default()` or the expression.
+//!
+//! Generic fields are converted, bounding the `OwnablePoly` impl on their types, unless
+//! `#[ownable(no_bound)]` is given on the type. `#[ownable(bound = "...")]` on the type adds
+//! where-predicates to the impl.
+//!
+//! `#[derive(BorrowPoly)]` implements `BorrowPoly`, the inverse of `OwnablePoly`, reborrowing an
+//! owned `MyType<'static>` as a `MyType<'a>` borrowing from it.
+//!
+//! `#[derive(MakeOwned)]` implements `MakeOwned`, owning the type's borrowed fields in place.
+//!
+//! `#[derive(ToStatic)]` generates a `MyTypeOwned` struct for a type whose reference fields
+//! `OwnablePoly` can't convert, with `String`, `Vec<T>` or other `ToOwned` fields in their place.
+//! `#[to_static(name = "...", derive(...))]` names the struct and adds derives to it.
+//!
+//! The `#[serde_poly]` attribute adds `Serialize`, `Deserialize`, `Poly` and `OwnablePoly` derives
+//! in one go.
 
+mod expand_borrow_poly;
+mod expand_make_owned;
 mod expand_ownable_poly;
 mod expand_poly;
+mod expand_poly_remote;
+mod expand_serde_poly;
+mod expand_to_static;
+mod lifetimes;
 
+use crate::expand_poly::{PolyImpls, expand_poly};
 use proc_macro::TokenStream;
-use syn::{
-    parse_macro_input,
-    DeriveInput,
-};
-use crate::expand_poly::expand_poly;
+use syn::{DeriveInput, parse_macro_input};
 
-#[proc_macro_derive(Poly, attributes(poly))]
+#[proc_macro_derive(Poly, attributes(poly, ownable))]
 pub fn derive_poly(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    match expand_poly(input) {
+    match expand_poly(input, PolyImpls::Both, None) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Like [`Poly`], but only implements [`SerializePoly`], for types that are never deserialized.
+#[proc_macro_derive(SerializePolyOnly, attributes(poly, ownable))]
+pub fn derive_serialize_poly_only(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_poly(input, PolyImpls::SerializeOnly, None) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Like [`Poly`], but only implements [`DeserializePoly`], for types that are never serialized.
+#[proc_macro_derive(DeserializePolyOnly, attributes(poly, ownable))]
+pub fn derive_deserialize_poly_only(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_poly(input, PolyImpls::DeserializeOnly, None) {
         Ok(tokens) => tokens.into(),
         Err(err) => err.to_compile_error().into(),
     }
 }
 
-#[proc_macro_derive(OwnablePoly)]
+#[proc_macro_derive(OwnablePoly, attributes(poly, ownable))]
 pub fn derive_ownable_poly(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match expand_ownable_poly::expand_ownable_poly(input) {
@@ -40,3 +118,67 @@ pub fn derive_ownable_poly(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Implements `BorrowPoly`, reborrowing each field that borrows the type's lifetimes from the
+/// value and cloning the rest.
+#[proc_macro_derive(BorrowPoly, attributes(poly))]
+pub fn derive_borrow_poly(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_borrow_poly::expand_borrow_poly(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implements `MakeOwned`, owning each field that borrows the type's lifetimes in place. Those
+/// fields must implement `MakeOwned` themselves, as `Cow` does, and can't be plain references.
+#[proc_macro_derive(MakeOwned, attributes(poly))]
+pub fn derive_make_owned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_make_owned::expand_make_owned(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generates an owned sibling of a struct, named with an `Owned` suffix, with its borrowed
+/// references replaced by their `ToOwned` types, plus a `From` impl converting to it and an
+/// `as_borrowed` method borrowing it back.
+#[proc_macro_derive(ToStatic, attributes(poly, to_static))]
+pub fn derive_to_static(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_to_static::expand_to_static(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives `Serialize`, `Deserialize`, [`Poly`] and [`OwnablePoly`] on the item. Its
+/// `#[serde(...)]` and `#[poly(...)]` attributes are left for those derives, and the serde derives
+/// are named through `::serde`, which must be a dependency of the crate.
+#[proc_macro_attribute]
+pub fn serde_poly(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_serde_poly::expand_serde_poly(args.into(), input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generates a Poly type for a type from another crate, given its signature:
+///
+/// ```ignore
+/// serde_poly::poly_remote!(pub struct RecordPoly for vendor::Record<'a, T> where T: Clone);
+/// ```
+///
+/// The Poly type implements [`DeserializePoly`] with `Out<'de> = vendor::Record<'de, T>`, and
+/// accepts the same `#[poly(...)]` attributes as the derive. The orphan rule forbids implementing
+/// [`SerializePoly`] for the foreign type, so only the deserialize half is generated.
+#[proc_macro]
+pub fn poly_remote(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as expand_poly_remote::PolyRemote);
+    match expand_poly_remote::expand_poly_remote(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
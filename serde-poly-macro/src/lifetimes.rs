@@ -0,0 +1,203 @@
+use syn::{
+    GenericArgument, Lifetime, Path, PathArguments, ReturnType, Type, TypeParamBound,
+    visit_mut::VisitMut,
+};
+
+/// Check if a type contains any of the specified lifetimes, including in fn pointers, trait
+/// objects and their higher-ranked bounds.
+pub(crate) fn type_contains_any_lifetime(ty: &Type, lifetimes: &[Lifetime]) -> bool {
+    match ty {
+        Type::Reference(type_ref) => {
+            // Check if the reference's lifetime matches any of our lifetimes
+            if let Some(ref lt) = type_ref.lifetime {
+                if is_any_lifetime(lt, lifetimes) {
+                    return true;
+                }
+            }
+            // Recursively check the referenced type
+            type_contains_any_lifetime(&type_ref.elem, lifetimes)
+        }
+        Type::Path(type_path) => {
+            if let Some(qself) = &type_path.qself {
+                if type_contains_any_lifetime(&qself.ty, lifetimes) {
+                    return true;
+                }
+            }
+            path_contains_any_lifetime(&type_path.path, lifetimes)
+        }
+        Type::BareFn(bare_fn) => {
+            bare_fn
+                .inputs
+                .iter()
+                .any(|arg| type_contains_any_lifetime(&arg.ty, lifetimes))
+                || return_contains_any_lifetime(&bare_fn.output, lifetimes)
+        }
+        Type::TraitObject(trait_object) => trait_object
+            .bounds
+            .iter()
+            .any(|bound| bound_contains_any_lifetime(bound, lifetimes)),
+        Type::ImplTrait(impl_trait) => impl_trait
+            .bounds
+            .iter()
+            .any(|bound| bound_contains_any_lifetime(bound, lifetimes)),
+        Type::Tuple(type_tuple) => type_tuple
+            .elems
+            .iter()
+            .any(|elem| type_contains_any_lifetime(elem, lifetimes)),
+        Type::Array(type_array) => type_contains_any_lifetime(&type_array.elem, lifetimes),
+        Type::Ptr(type_ptr) => type_contains_any_lifetime(&type_ptr.elem, lifetimes),
+        Type::Slice(type_slice) => type_contains_any_lifetime(&type_slice.elem, lifetimes),
+        Type::Paren(type_paren) => type_contains_any_lifetime(&type_paren.elem, lifetimes),
+        Type::Group(type_group) => type_contains_any_lifetime(&type_group.elem, lifetimes),
+        _ => false,
+    }
+}
+
+/// Find a fn pointer or trait object within a type that mentions any of the specified lifetimes.
+/// These can't be converted to owned data, as there's nothing to copy the borrow out of.
+pub(crate) fn find_erased_borrow<'t>(ty: &'t Type, lifetimes: &[Lifetime]) -> Option<&'t Type> {
+    match ty {
+        Type::BareFn(_) | Type::TraitObject(_) | Type::ImplTrait(_) => {
+            type_contains_any_lifetime(ty, lifetimes).then_some(ty)
+        }
+        Type::Reference(type_ref) => find_erased_borrow(&type_ref.elem, lifetimes),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .iter()
+            .filter_map(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(args) => Some(args),
+                _ => None,
+            })
+            .flat_map(|args| &args.args)
+            .find_map(|arg| match arg {
+                GenericArgument::Type(inner_ty) => find_erased_borrow(inner_ty, lifetimes),
+                GenericArgument::AssocType(assoc) => find_erased_borrow(&assoc.ty, lifetimes),
+                _ => None,
+            }),
+        Type::Tuple(type_tuple) => type_tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_erased_borrow(elem, lifetimes)),
+        Type::Array(type_array) => find_erased_borrow(&type_array.elem, lifetimes),
+        Type::Ptr(type_ptr) => find_erased_borrow(&type_ptr.elem, lifetimes),
+        Type::Slice(type_slice) => find_erased_borrow(&type_slice.elem, lifetimes),
+        Type::Paren(type_paren) => find_erased_borrow(&type_paren.elem, lifetimes),
+        Type::Group(type_group) => find_erased_borrow(&type_group.elem, lifetimes),
+        _ => None,
+    }
+}
+
+/// Find a reference within a type that borrows any of the specified lifetimes, outside of fn
+/// pointers and trait objects. These can only be made `'static` by changing their type.
+pub(crate) fn find_borrowed_reference<'t>(
+    ty: &'t Type,
+    lifetimes: &[Lifetime],
+) -> Option<&'t syn::TypeReference> {
+    match ty {
+        Type::Reference(type_ref) => match &type_ref.lifetime {
+            Some(lifetime) if is_any_lifetime(lifetime, lifetimes) => Some(type_ref),
+            _ => find_borrowed_reference(&type_ref.elem, lifetimes),
+        },
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .iter()
+            .filter_map(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(args) => Some(args),
+                _ => None,
+            })
+            .flat_map(|args| &args.args)
+            .find_map(|arg| match arg {
+                GenericArgument::Type(inner_ty) => find_borrowed_reference(inner_ty, lifetimes),
+                GenericArgument::AssocType(assoc) => find_borrowed_reference(&assoc.ty, lifetimes),
+                _ => None,
+            }),
+        Type::Tuple(type_tuple) => type_tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_borrowed_reference(elem, lifetimes)),
+        Type::Array(type_array) => find_borrowed_reference(&type_array.elem, lifetimes),
+        Type::Slice(type_slice) => find_borrowed_reference(&type_slice.elem, lifetimes),
+        Type::Paren(type_paren) => find_borrowed_reference(&type_paren.elem, lifetimes),
+        Type::Group(type_group) => find_borrowed_reference(&type_group.elem, lifetimes),
+        _ => None,
+    }
+}
+
+fn is_any_lifetime(lifetime: &Lifetime, lifetimes: &[Lifetime]) -> bool {
+    lifetimes
+        .iter()
+        .any(|param_lt| lifetime.ident == param_lt.ident)
+}
+
+fn path_contains_any_lifetime(path: &Path, lifetimes: &[Lifetime]) -> bool {
+    // Check if any generic arguments contain our lifetimes
+    path.segments
+        .iter()
+        .any(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+                GenericArgument::Lifetime(lt) => is_any_lifetime(lt, lifetimes),
+                GenericArgument::Type(inner_ty) => type_contains_any_lifetime(inner_ty, lifetimes),
+                GenericArgument::AssocType(assoc) => {
+                    type_contains_any_lifetime(&assoc.ty, lifetimes)
+                }
+                GenericArgument::Constraint(constraint) => constraint
+                    .bounds
+                    .iter()
+                    .any(|bound| bound_contains_any_lifetime(bound, lifetimes)),
+                _ => false,
+            }),
+            // `Fn(&'a str) -> &'a str` sugar
+            PathArguments::Parenthesized(args) => {
+                args.inputs
+                    .iter()
+                    .any(|input| type_contains_any_lifetime(input, lifetimes))
+                    || return_contains_any_lifetime(&args.output, lifetimes)
+            }
+            PathArguments::None => false,
+        })
+}
+
+/// Check a `'a` or `for<'x> Trait<'a>` bound. Binders can't shadow the type's lifetimes, so any
+/// match is one of ours.
+pub(crate) fn bound_contains_any_lifetime(bound: &TypeParamBound, lifetimes: &[Lifetime]) -> bool {
+    match bound {
+        TypeParamBound::Lifetime(lt) => is_any_lifetime(lt, lifetimes),
+        TypeParamBound::Trait(trait_bound) => {
+            path_contains_any_lifetime(&trait_bound.path, lifetimes)
+        }
+        _ => false,
+    }
+}
+
+fn return_contains_any_lifetime(output: &ReturnType, lifetimes: &[Lifetime]) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => type_contains_any_lifetime(ty, lifetimes),
+        ReturnType::Default => false,
+    }
+}
+
+/// Rewrites the lifetimes in a syntax tree, recording whether any were replaced.
+pub(crate) struct MapLifetimes<F> {
+    map: F,
+    pub(crate) replaced: bool,
+}
+
+impl<F: FnMut(&Lifetime) -> Option<Lifetime>> MapLifetimes<F> {
+    pub(crate) fn new(map: F) -> Self {
+        MapLifetimes {
+            map,
+            replaced: false,
+        }
+    }
+}
+
+impl<F: FnMut(&Lifetime) -> Option<Lifetime>> VisitMut for MapLifetimes<F> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if let Some(mapped) = (self.map)(lifetime) {
+            *lifetime = mapped;
+            self.replaced = true;
+        }
+    }
+}
@@ -1,5 +1,13 @@
-use crate::OwnablePoly;
+use crate::{BorrowPoly, MakeOwned, OwnablePoly};
 use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::num::{Saturating, Wrapping};
+use std::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::rc::Rc;
+use std::sync::Arc;
 
 macro_rules! impl_ownable_poly_primitive {
     ($($t:ty),*) => {
@@ -9,20 +17,240 @@ macro_rules! impl_ownable_poly_primitive {
                 fn into_owned(self) -> Self::Owned {
                     self
                 }
+                crate::__impl_ownable_identity!();
+            }
+
+            impl MakeOwned for $t {
+                fn make_owned(&mut self) {}
+            }
+
+            impl BorrowPoly for $t {
+                type Borrowed<'a> = Self;
+
+                #[allow(clippy::clone_on_copy)]
+                fn reborrow(&self) -> Self {
+                    self.clone()
+                }
             }
         )*
     };
 }
 
-impl_ownable_poly_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_ownable_poly_primitive!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
 impl_ownable_poly_primitive!(char, String);
 impl_ownable_poly_primitive!(bool);
-impl_ownable_poly_primitive!(());
+impl_ownable_poly_primitive!((), std::ops::RangeFull, std::cmp::Ordering);
+impl_ownable_poly_primitive!(
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize
+);
+
+impl_ownable_poly_primitive!(
+    std::time::Duration,
+    std::time::Instant,
+    std::time::SystemTime,
+    std::net::IpAddr,
+    std::net::Ipv4Addr,
+    std::net::Ipv6Addr,
+    std::net::SocketAddr,
+    std::net::SocketAddrV4,
+    std::net::SocketAddrV6,
+    std::path::PathBuf,
+    std::ffi::OsString,
+    std::ffi::CString
+);
+
+/// Only markers of `'static` types are supported, and they are returned as-is. The derive builds a
+/// new `PhantomData` for its fields instead, so they may mark borrowed types too.
+impl<T: ?Sized + 'static> OwnablePoly for PhantomData<T> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+impl<T: ?Sized> MakeOwned for PhantomData<T> {
+    fn make_owned(&mut self) {}
+}
+
+impl<T: OwnablePoly> OwnablePoly for Wrapping<T> {
+    type Owned = Wrapping<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        Wrapping(self.0.into_owned())
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for Saturating<T> {
+    type Owned = Saturating<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        Saturating(self.0.into_owned())
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for Reverse<T> {
+    type Owned = Reverse<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        Reverse(self.0.into_owned())
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for Bound<T> {
+    type Owned = Bound<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.map(T::into_owned)
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for Range<T> {
+    type Owned = Range<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.start.into_owned()..self.end.into_owned()
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for RangeInclusive<T> {
+    type Owned = RangeInclusive<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        let (start, end) = self.into_inner();
+        start.into_owned()..=end.into_owned()
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for RangeFrom<T> {
+    type Owned = RangeFrom<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.start.into_owned()..
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for RangeTo<T> {
+    type Owned = RangeTo<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        ..self.end.into_owned()
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for RangeToInclusive<T> {
+    type Owned = RangeToInclusive<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        ..=self.end.into_owned()
+    }
+}
+
+/// Converts the elements of a `Vec`. std's in-place `collect` reuses the buffer when the owned
+/// element has the same layout, so the `VecDeque`, `BinaryHeap` and boxed slice impls go through
+/// it too.
+fn owned_vec<T: OwnablePoly>(vec: Vec<T>) -> Vec<T::Owned> {
+    vec.into_iter().map(T::into_owned).collect()
+}
 
 impl<T: OwnablePoly> OwnablePoly for Vec<T> {
     type Owned = Vec<T::Owned>;
     fn into_owned(self) -> Self::Owned {
-        self.into_iter().map(|x| x.into_owned()).collect()
+        owned_vec(self)
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for Box<T> {
+    type Owned = Box<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        T::__into_owned_box(self)
+    }
+}
+
+impl OwnablePoly for Box<str> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+impl<T: OwnablePoly> OwnablePoly for Box<[T]> {
+    type Owned = Box<[T::Owned]>;
+    fn into_owned(self) -> Self::Owned {
+        owned_vec(self.into_vec()).into_boxed_slice()
+    }
+}
+
+/// Moves the value out of the `Arc` when it's the only owner, and clones it otherwise, so `T` must
+/// be `Clone` even when the `Arc` is never shared. Already owned values keep their `Arc`. As with
+/// `BinaryHeap`, the owned type must be its own owned type, and `Clone` as well.
+impl<T> OwnablePoly for Arc<T>
+where
+    T: OwnablePoly + Clone,
+    T::Owned: OwnablePoly<Owned = T::Owned> + Clone,
+{
+    type Owned = Arc<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        T::__into_owned_arc(self)
+    }
+}
+
+/// See the [`Arc`] impl.
+impl<T> OwnablePoly for Rc<T>
+where
+    T: OwnablePoly + Clone,
+    T::Owned: OwnablePoly<Owned = T::Owned> + Clone,
+{
+    type Owned = Rc<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        T::__into_owned_rc(self)
+    }
+}
+
+impl OwnablePoly for Arc<str> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+impl OwnablePoly for Rc<str> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+impl<T: OwnablePoly> OwnablePoly for VecDeque<T> {
+    type Owned = VecDeque<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        owned_vec(self.into()).into()
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for LinkedList<T> {
+    type Owned = LinkedList<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(T::into_owned).collect()
+    }
+}
+
+/// See the [`HashMap`] impl.
+impl<T> OwnablePoly for BinaryHeap<T>
+where
+    T: OwnablePoly,
+    T::Owned: OwnablePoly<Owned = T::Owned> + Ord,
+{
+    type Owned = BinaryHeap<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        owned_vec(self.into_vec()).into()
     }
 }
 
@@ -43,6 +271,147 @@ impl<T: OwnablePoly, E: OwnablePoly> OwnablePoly for Result<T, E> {
     }
 }
 
+/// Each element of a tuple is converted independently, for tuples of up to 16 elements.
+macro_rules! impl_ownable_poly_tuple {
+    ($(($($n:tt $t:ident)+))+) => {
+        $(
+            impl<$($t: OwnablePoly),+> OwnablePoly for ($($t,)+) {
+                type Owned = ($($t::Owned,)+);
+                fn into_owned(self) -> Self::Owned {
+                    ($(self.$n.into_owned(),)+)
+                }
+            }
+        )+
+    };
+}
+
+impl_ownable_poly_tuple! {
+    (0 T0)
+    (0 T0 1 T1)
+    (0 T0 1 T1 2 T2)
+    (0 T0 1 T1 2 T2 3 T3)
+    (0 T0 1 T1 2 T2 3 T3 4 T4)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14)
+    (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 15 T15)
+}
+
+impl<T: OwnablePoly, const N: usize> OwnablePoly for [T; N] {
+    type Owned = [T::Owned; N];
+    fn into_owned(self) -> Self::Owned {
+        self.map(T::into_owned)
+    }
+}
+
+/// Owned keys must be their own owned type, as `Cow<'static, str>` and `String` are, so the owned
+/// map's keys keep their `Hash` and `Eq` impls when converted again.
+impl<K, V, S> OwnablePoly for HashMap<K, V, S>
+where
+    K: OwnablePoly,
+    K::Owned: OwnablePoly<Owned = K::Owned> + Hash + Eq,
+    V: OwnablePoly,
+    S: BuildHasher + Clone + 'static,
+{
+    type Owned = HashMap<K::Owned, V::Owned, S>;
+    fn into_owned(self) -> Self::Owned {
+        let mut map = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(
+            self.into_iter()
+                .map(|(k, v)| (k.into_owned(), v.into_owned())),
+        );
+        map
+    }
+}
+
+/// See the [`HashMap`] impl.
+impl<K, V> OwnablePoly for BTreeMap<K, V>
+where
+    K: OwnablePoly,
+    K::Owned: OwnablePoly<Owned = K::Owned> + Ord,
+    V: OwnablePoly,
+{
+    type Owned = BTreeMap<K::Owned, V::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect()
+    }
+}
+
+/// See the [`HashMap`] impl.
+impl<T, S> OwnablePoly for HashSet<T, S>
+where
+    T: OwnablePoly,
+    T::Owned: OwnablePoly<Owned = T::Owned> + Hash + Eq,
+    S: BuildHasher + Clone + 'static,
+{
+    type Owned = HashSet<T::Owned, S>;
+    fn into_owned(self) -> Self::Owned {
+        let mut set = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.into_iter().map(T::into_owned));
+        set
+    }
+}
+
+/// See the [`HashMap`] impl.
+impl<T> OwnablePoly for BTreeSet<T>
+where
+    T: OwnablePoly,
+    T::Owned: OwnablePoly<Owned = T::Owned> + Ord,
+{
+    type Owned = BTreeSet<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(T::into_owned).collect()
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for std::cell::OnceCell<T> {
+    type Owned = std::cell::OnceCell<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        match self.into_inner() {
+            Some(x) => x.into_owned().into(),
+            None => std::cell::OnceCell::new(),
+        }
+    }
+}
+
+impl<T: OwnablePoly> OwnablePoly for std::sync::OnceLock<T> {
+    type Owned = std::sync::OnceLock<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        match self.into_inner() {
+            Some(x) => x.into_owned().into(),
+            None => std::sync::OnceLock::new(),
+        }
+    }
+}
+
+/// A weak pointer can't be converted without detaching it from its strong owners, so only weak
+/// pointers to `'static` values are supported, and they are returned as-is.
+impl<T: ?Sized + 'static> OwnablePoly for std::rc::Weak<T> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+/// See the [`std::rc::Weak`] impl.
+impl<T: ?Sized + 'static> OwnablePoly for std::sync::Weak<T> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
 impl<'a, B> OwnablePoly for Cow<'a, B>
 where
     B: ToOwned + ?Sized,
@@ -56,5 +425,163 @@ where
     }
 }
 
+impl<B> MakeOwned for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+{
+    fn make_owned(&mut self) {
+        if let Cow::Borrowed(borrowed) = *self {
+            *self = Cow::Owned(borrowed.to_owned());
+        }
+    }
+}
+
+impl<T: MakeOwned> MakeOwned for Vec<T> {
+    fn make_owned(&mut self) {
+        self.iter_mut().for_each(MakeOwned::make_owned);
+    }
+}
+
+impl<T: MakeOwned> MakeOwned for Option<T> {
+    fn make_owned(&mut self) {
+        if let Some(x) = self {
+            x.make_owned();
+        }
+    }
+}
+
+impl<T: MakeOwned, E: MakeOwned> MakeOwned for Result<T, E> {
+    fn make_owned(&mut self) {
+        match self {
+            Ok(v) => v.make_owned(),
+            Err(e) => e.make_owned(),
+        }
+    }
+}
+
+impl<B> BorrowPoly for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+{
+    type Borrowed<'a>
+        = Cow<'a, B>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Cow<'_, B> {
+        Cow::Borrowed(&**self)
+    }
+}
+
+impl<T: BorrowPoly> BorrowPoly for Vec<T> {
+    type Borrowed<'a>
+        = Vec<T::Borrowed<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_> {
+        self.iter().map(BorrowPoly::reborrow).collect()
+    }
+}
+
+impl<T: BorrowPoly> BorrowPoly for Option<T> {
+    type Borrowed<'a>
+        = Option<T::Borrowed<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_> {
+        self.as_ref().map(BorrowPoly::reborrow)
+    }
+}
+
+impl<T: BorrowPoly, E: BorrowPoly> BorrowPoly for Result<T, E> {
+    type Borrowed<'a>
+        = Result<T::Borrowed<'a>, E::Borrowed<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_> {
+        match self {
+            Ok(v) => Ok(v.reborrow()),
+            Err(e) => Err(e.reborrow()),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone + 'static> OwnablePoly for chrono::DateTime<Tz> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+#[cfg(feature = "chrono")]
+impl_ownable_poly_primitive!(chrono::NaiveDate, chrono::NaiveTime, chrono::NaiveDateTime);
+
+#[cfg(feature = "chrono-tz")]
+impl_ownable_poly_primitive!(chrono_tz::Tz);
+
+/// Arenas can't be rebuilt with their original indices and generations, so only arenas of
+/// already-owned values are supported.
+#[cfg(feature = "generational-arena")]
+impl<T: OwnablePoly<Owned = T> + 'static> OwnablePoly for generational_arena::Arena<T> {
+    type Owned = Self;
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+    crate::__impl_ownable_identity!();
+}
+
+#[cfg(feature = "half")]
+impl_ownable_poly_primitive!(half::f16, half::bf16);
+
+#[cfg(feature = "humantime")]
+impl_ownable_poly_primitive!(crate::HumanDuration);
+
+#[cfg(feature = "serde_json")]
+impl<T: 'static> OwnablePoly for crate::NestedJson<'_, T> {
+    type Owned = crate::NestedJson<'static, T>;
+
+    fn into_owned(self) -> Self::Owned {
+        crate::NestedJson::into_owned(self)
+    }
+}
+
+#[cfg(feature = "slab")]
+impl<T: OwnablePoly> OwnablePoly for slab::Slab<T> {
+    type Owned = slab::Slab<T::Owned>;
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(|(k, v)| (k, v.into_owned())).collect()
+    }
+}
+
 #[cfg(feature = "uuid")]
 impl_ownable_poly_primitive!(uuid::Uuid);
+
+#[cfg(feature = "zerovec")]
+impl<T> OwnablePoly for zerovec::ZeroVec<'_, T>
+where
+    T: zerovec::ule::AsULE + 'static,
+{
+    type Owned = zerovec::ZeroVec<'static, T>;
+
+    fn into_owned(self) -> Self::Owned {
+        zerovec::ZeroVec::into_owned(self)
+    }
+}
+
+#[cfg(feature = "zerovec")]
+impl<T, F> OwnablePoly for zerovec::VarZeroVec<'_, T, F>
+where
+    T: zerovec::ule::VarULE + ?Sized + 'static,
+    F: zerovec::vecs::VarZeroVecFormat,
+{
+    type Owned = zerovec::VarZeroVec<'static, T, F>;
+
+    fn into_owned(self) -> Self::Owned {
+        zerovec::VarZeroVec::into_owned(self)
+    }
+}
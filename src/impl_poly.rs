@@ -1,4 +1,4 @@
-use crate::{DeserializePoly, SerializePoly};
+use crate::{DeserializePoly, PolyMarker, PolyPair, SerializePoly};
 
 macro_rules! impl_poly_owned {
     ($name:ty) => {
@@ -9,27 +9,93 @@ macro_rules! impl_poly_owned {
         impl SerializePoly for $name {
             type Out = Self;
         }
+
+        impl PolyPair for $name {
+            type Marker = Self;
+        }
     };
 
-    ($generic:ident, $name:ty) => {
-        impl<$generic> DeserializePoly for $name
+    ($generic:ident $(: $bound:path)?, $name:ty) => {
+        impl<$generic $(: $bound)?> DeserializePoly for $name
         where
             $name: for <'de> serde::Deserialize<'de>,
         {
             type Out<'de> = Self;
         }
 
-        impl<$generic> SerializePoly for $name
+        impl<$generic $(: $bound)?> SerializePoly for $name
         where
             $name: serde::Serialize,
         {
             type Out = Self;
         }
+
+        impl<$generic $(: $bound)?> PolyPair for $name
+        where
+            $name: DeserializePoly,
+        {
+            type Marker = Self;
+        }
+    };
+}
+
+/// Implement the std traits for a generic marker without bounding its type parameters, which
+/// `#[derive]` would.
+#[cfg_attr(
+    not(any(feature = "serde_json", feature = "zerovec")),
+    allow(unused_macros)
+)]
+macro_rules! impl_marker_traits {
+    ($poly:ident<$($param:ident $(: ?$sized:ident)?),*> = $default:expr) => {
+        impl<$($param $(: ?$sized)?),*> Default for $poly<$($param),*> {
+            fn default() -> Self {
+                $default
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> Clone for $poly<$($param),*> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> Copy for $poly<$($param),*> {}
+
+        impl<$($param $(: ?$sized)?),*> core::fmt::Debug for $poly<$($param),*> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(stringify!($poly))
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> PartialEq for $poly<$($param),*> {
+            fn eq(&self, _: &Self) -> bool {
+                true
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> Eq for $poly<$($param),*> {}
+
+        impl<$($param $(: ?$sized)?),*> PartialOrd for $poly<$($param),*> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> Ord for $poly<$($param),*> {
+            fn cmp(&self, _: &Self) -> core::cmp::Ordering {
+                core::cmp::Ordering::Equal
+            }
+        }
+
+        impl<$($param $(: ?$sized)?),*> core::hash::Hash for $poly<$($param),*> {
+            fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+        }
     };
 }
 
 macro_rules! impl_poly_borrowed {
     ($name:ty, $poly:ident) => {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $poly {}
 
         impl DeserializePoly for $poly {
@@ -39,11 +105,17 @@ macro_rules! impl_poly_borrowed {
         impl<'de> SerializePoly for $name {
             type Out = $poly;
         }
+
+        impl<'de> PolyPair for $name {
+            type Marker = $poly;
+        }
     };
 
     ($generic:ident, $name:ty, $named:ty, $poly:ident) => {
         pub struct $poly<$generic>(core::marker::PhantomData<$generic>);
 
+        impl_marker_traits!($poly<$generic> = Self(core::marker::PhantomData));
+
         impl<'d, $generic> DeserializePoly for $poly<$generic>
         where
             $named: serde::Deserialize<'d>,
@@ -52,9 +124,19 @@ macro_rules! impl_poly_borrowed {
             type Out<'de> = $name;
         }
 
-        impl<'d, $generic> SerializePoly for $named where $named: serde::Serialize {
+        impl<'d, $generic> SerializePoly for $named
+        where
+            $named: serde::Serialize,
+        {
             type Out = $poly<$generic>;
         }
+
+        impl<'d, $generic> PolyPair for $named
+        where
+            $poly<$generic>: DeserializePoly,
+        {
+            type Marker = $poly<$generic>;
+        }
     };
 }
 
@@ -78,7 +160,154 @@ impl_poly_owned!(f32);
 impl_poly_owned!(f64);
 impl_poly_owned!(T, Vec<T>);
 
+// serde serializes `Weak<T>` as an `Option<T>` of its upgraded value, and always deserializes an
+// empty `Weak` that cannot be upgraded.
+#[cfg(feature = "rc")]
+impl_poly_owned!(T, std::rc::Weak<T>);
+#[cfg(feature = "rc")]
+impl_poly_owned!(T, std::sync::Weak<T>);
+
 impl_poly_borrowed!(&'de str, StrPoly);
 
+impl PolyMarker for StrPoly {
+    const TYPE_NAME: &'static str = "str";
+}
+
+#[cfg(feature = "chrono")]
+impl_poly_owned!(Tz: chrono::TimeZone, chrono::DateTime<Tz>);
+#[cfg(feature = "chrono")]
+impl_poly_owned!(chrono::NaiveDate);
+#[cfg(feature = "chrono")]
+impl_poly_owned!(chrono::NaiveTime);
+#[cfg(feature = "chrono")]
+impl_poly_owned!(chrono::NaiveDateTime);
+
+#[cfg(feature = "chrono-tz")]
+impl_poly_owned!(chrono_tz::Tz);
+
+#[cfg(feature = "generational-arena")]
+impl_poly_owned!(T, generational_arena::Arena<T>);
+
+#[cfg(feature = "half")]
+impl_poly_owned!(half::f16);
+#[cfg(feature = "half")]
+impl_poly_owned!(half::bf16);
+
+#[cfg(feature = "humantime")]
+impl_poly_owned!(crate::HumanDuration);
+
+#[cfg(feature = "slab")]
+impl_poly_owned!(T, slab::Slab<T>);
+
 #[cfg(feature = "uuid")]
 impl_poly_owned!(uuid::Uuid);
+
+/// The [`SerializePoly::Out`] marker for [`NestedJson`](crate::NestedJson).
+#[cfg(feature = "serde_json")]
+pub struct NestedJsonPoly<T>(core::marker::PhantomData<fn() -> T>);
+
+#[cfg(feature = "serde_json")]
+impl_marker_traits!(NestedJsonPoly<T> = Self(core::marker::PhantomData));
+
+#[cfg(feature = "serde_json")]
+impl<T> PolyMarker for NestedJsonPoly<T> {
+    const TYPE_NAME: &'static str = "NestedJson";
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> DeserializePoly for NestedJsonPoly<T> {
+    type Out<'de> = crate::NestedJson<'de, T>;
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> SerializePoly for crate::NestedJson<'_, T> {
+    type Out = NestedJsonPoly<T>;
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> PolyPair for crate::NestedJson<'_, T> {
+    type Marker = NestedJsonPoly<T>;
+}
+
+/// The [`SerializePoly::Out`] marker for [`zerovec::ZeroVec`].
+#[cfg(feature = "zerovec")]
+pub struct ZeroVecPoly<T>(core::marker::PhantomData<fn() -> T>);
+
+#[cfg(feature = "zerovec")]
+impl_marker_traits!(ZeroVecPoly<T> = Self(core::marker::PhantomData));
+
+#[cfg(feature = "zerovec")]
+impl<T> PolyMarker for ZeroVecPoly<T> {
+    const TYPE_NAME: &'static str = "ZeroVec";
+}
+
+#[cfg(feature = "zerovec")]
+impl<T> DeserializePoly for ZeroVecPoly<T>
+where
+    T: zerovec::ule::AsULE + for<'de> serde::Deserialize<'de> + 'static,
+{
+    type Out<'de> = zerovec::ZeroVec<'de, T>;
+}
+
+#[cfg(feature = "zerovec")]
+impl<T> SerializePoly for zerovec::ZeroVec<'_, T>
+where
+    T: zerovec::ule::AsULE + serde::Serialize + 'static,
+{
+    type Out = ZeroVecPoly<T>;
+}
+
+#[cfg(feature = "zerovec")]
+impl<T> PolyPair for zerovec::ZeroVec<'_, T>
+where
+    T: zerovec::ule::AsULE + 'static,
+    ZeroVecPoly<T>: DeserializePoly,
+{
+    type Marker = ZeroVecPoly<T>;
+}
+
+/// The [`SerializePoly::Out`] marker for [`zerovec::VarZeroVec`].
+#[cfg(feature = "zerovec")]
+pub struct VarZeroVecPoly<T: ?Sized, F = zerovec::vecs::Index16>(
+    core::marker::PhantomData<fn() -> Box<T>>,
+    core::marker::PhantomData<fn() -> F>,
+);
+
+#[cfg(feature = "zerovec")]
+impl_marker_traits!(
+    VarZeroVecPoly<T: ?Sized, F> = Self(core::marker::PhantomData, core::marker::PhantomData)
+);
+
+#[cfg(feature = "zerovec")]
+impl<T: ?Sized, F> PolyMarker for VarZeroVecPoly<T, F> {
+    const TYPE_NAME: &'static str = "VarZeroVec";
+}
+
+#[cfg(feature = "zerovec")]
+impl<T, F> DeserializePoly for VarZeroVecPoly<T, F>
+where
+    T: zerovec::ule::VarULE + ?Sized + 'static,
+    Box<T>: for<'de> serde::Deserialize<'de>,
+    F: zerovec::vecs::VarZeroVecFormat,
+{
+    type Out<'de> = zerovec::VarZeroVec<'de, T, F>;
+}
+
+#[cfg(feature = "zerovec")]
+impl<T, F> SerializePoly for zerovec::VarZeroVec<'_, T, F>
+where
+    T: zerovec::ule::VarULE + serde::Serialize + ?Sized + 'static,
+    F: zerovec::vecs::VarZeroVecFormat,
+{
+    type Out = VarZeroVecPoly<T, F>;
+}
+
+#[cfg(feature = "zerovec")]
+impl<T, F> PolyPair for zerovec::VarZeroVec<'_, T, F>
+where
+    T: zerovec::ule::VarULE + ?Sized + 'static,
+    F: zerovec::vecs::VarZeroVecFormat,
+    VarZeroVecPoly<T, F>: DeserializePoly,
+{
+    type Marker = VarZeroVecPoly<T, F>;
+}
@@ -1,8 +1,34 @@
-mod impl_poly;
+mod content;
+#[cfg(feature = "humantime")]
+mod human_duration;
 mod impl_ownable_poly;
+mod impl_poly;
+mod macros;
+#[cfg(feature = "serde_json")]
+mod nested_json;
+mod tagged;
+mod versioned;
+#[cfg(feature = "serde_json")]
+pub mod with;
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
 
 use serde::Deserialize;
-pub use serde_poly_macro::{OwnablePoly, Poly};
+pub use serde_poly_macro::{
+    BorrowPoly, DeserializePolyOnly, MakeOwned, OwnablePoly, Poly, SerializePolyOnly, ToStatic,
+    poly_remote, serde_poly,
+};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[cfg(feature = "humantime")]
+pub use human_duration::HumanDuration;
+#[cfg(feature = "serde_json")]
+pub use nested_json::{DecodeFailure, NestedJson};
+pub use tagged::Tagged;
+pub use versioned::Versioned;
 
 /// A disjoint marker trait to hide the lifetimes of the deserializable types. All types must
 /// implement this trait to be used as type parameters in the serialization wrappers.
@@ -26,6 +52,43 @@ pub trait SerializePoly: serde::Serialize {
     type Out;
 }
 
+/// Associates a type with its Poly type, so generic code can name the Poly type of `T<'a>` in
+/// bounds. The Poly type maps back to the type through [`DeserializePoly::Out`].
+pub trait PolyPair {
+    type Marker: DeserializePoly;
+}
+
+/// Implemented by Poly types to name the type they stand for at runtime, e.g. `"MyType"` for
+/// `MyTypePoly`, for logging and storage metadata. Owned types are their own Poly type.
+pub trait PolyMarker {
+    const TYPE_NAME: &'static str;
+}
+
+/// The tag of a type's [`Tagged`] envelope, implemented by `#[poly(tag = "...")]`.
+pub trait PolyTag {
+    const TAG: &'static str;
+}
+
+/// The schema version of a type in a [`Versioned`] envelope, implemented by
+/// `#[poly(version = N)]`.
+pub trait PolyVersion {
+    const VERSION: u32;
+}
+
+/// Deserialize a type from any of its schema versions, implemented by `#[poly(version = N)]`.
+/// Older versions are deserialized as the `#[poly(upgrade_from = "...")]` type and converted with
+/// [`Upgrade`], recursively.
+pub trait DeserializeVersion<'de>: PolyVersion + Deserialize<'de> {
+    fn deserialize_version<D>(version: u32, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>;
+}
+
+/// Convert the previous schema version of a type into the current one.
+pub trait Upgrade<From>: Sized {
+    fn upgrade(from: From) -> Self;
+}
+
 /// A disjoint marker trait for types that implement both [`DeserializePoly`] and [`SerializePoly`].
 pub trait SerdePoly: DeserializePoly + SerializePoly {}
 impl<T> SerdePoly for T where T: DeserializePoly + SerializePoly {}
@@ -38,4 +101,89 @@ pub trait OwnablePoly {
     type Owned: OwnablePoly + 'static;
 
     fn into_owned(self) -> Self::Owned;
+
+    /// Converts a boxed value, which `Box<Self>` uses for its own conversion. The derive and the
+    /// crate's own impls of types that are their own owned type override it to reuse the
+    /// allocation.
+    ///
+    /// Not public API: this hook is an implementation detail of the `Box` impl, exempt from semver,
+    /// and may change or go away in any release. Don't call or override it outside serde-poly.
+    #[doc(hidden)]
+    #[allow(clippy::boxed_local)]
+    fn __into_owned_box(boxed: Box<Self>) -> Box<Self::Owned>
+    where
+        Self: Sized,
+    {
+        Box::new((*boxed).into_owned())
+    }
+
+    /// Converts a shared value, which `Arc<Self>` uses for its own conversion. The value is moved
+    /// out when the `Arc` is its only owner and cloned otherwise, which is why it needs
+    /// `Self: Clone`, and types that are their own owned type override it to keep the `Arc`, along
+    /// with its other owners.
+    ///
+    /// Not public API, like [`__into_owned_box`](Self::__into_owned_box).
+    #[doc(hidden)]
+    fn __into_owned_arc(arc: Arc<Self>) -> Arc<Self::Owned>
+    where
+        Self: Sized + Clone,
+    {
+        Arc::new(Arc::unwrap_or_clone(arc).into_owned())
+    }
+
+    /// The [`Rc`] counterpart of [`__into_owned_arc`](Self::__into_owned_arc), and not public API
+    /// either.
+    #[doc(hidden)]
+    fn __into_owned_rc(rc: Rc<Self>) -> Rc<Self::Owned>
+    where
+        Self: Sized + Clone,
+    {
+        Rc::new(Rc::unwrap_or_clone(rc).into_owned())
+    }
+
+    /// Converts a clone of the value, keeping the original for further zero-copy reads. Named to
+    /// avoid ambiguity with [`ToOwned::to_owned`], which every `Clone` type implements.
+    fn to_owned_poly(&self) -> Self::Owned
+    where
+        Self: Clone,
+    {
+        self.clone().into_owned()
+    }
+}
+
+/// The owned, `'static` form of `T`, short for `<T as OwnablePoly>::Owned`.
+pub type OwnedOf<T> = <T as OwnablePoly>::Owned;
+
+/// An extension trait naming [`OwnablePoly::into_owned`] by its purpose, implemented for every
+/// [`OwnablePoly`] type.
+///
+/// Unlike `into_owned`, it isn't shadowed by inherent methods such as [`Cow::into_owned`], which
+/// returns the borrowed type's owned form rather than a `Cow<'static, B>`.
+///
+/// [`Cow::into_owned`]: std::borrow::Cow::into_owned
+pub trait IntoStatic: OwnablePoly + Sized {
+    fn into_static(self) -> OwnedOf<Self>;
+}
+
+impl<T: OwnablePoly> IntoStatic for T {
+    fn into_static(self) -> OwnedOf<Self> {
+        self.into_owned()
+    }
+}
+
+/// A type whose borrowed data can be replaced by owned data in place, such as a struct of `Cow`
+/// fields. Unlike [`OwnablePoly::into_owned`], the value keeps its lifetimes and isn't moved, so a
+/// large struct can be detached from its input without rebuilding it.
+pub trait MakeOwned {
+    fn make_owned(&mut self);
+}
+
+/// The inverse of [`OwnablePoly`], reborrowing an owned value such as `Type<'static>` as a
+/// `Type<'a>` that borrows from it, e.g. with `Cow::Borrowed` over its owned data.
+pub trait BorrowPoly {
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_>;
 }
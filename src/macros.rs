@@ -0,0 +1,214 @@
+/// Implement [`DeserializePoly`](crate::DeserializePoly) and [`SerializePoly`](crate::SerializePoly)
+/// with `Self`, and an identity [`OwnablePoly`](crate::OwnablePoly), for owned types that can't
+/// use the derives, such as those generated by `bitflags!`.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// bitflags::bitflags! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///     pub struct Permissions: u8 {
+///         const READ = 1;
+///         const WRITE = 2;
+///     }
+/// }
+///
+/// serde_poly::poly_bitflags!(Permissions);
+/// ```
+#[macro_export]
+macro_rules! poly_bitflags {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl $crate::DeserializePoly for $name {
+                type Out<'de> = Self;
+            }
+
+            impl $crate::SerializePoly for $name {
+                type Out = Self;
+            }
+
+            impl $crate::PolyPair for $name {
+                type Marker = Self;
+            }
+
+            impl $crate::OwnablePoly for $name {
+                type Owned = Self;
+                fn into_owned(self) -> Self::Owned {
+                    self
+                }
+                $crate::__impl_ownable_identity!();
+            }
+        )+
+    };
+}
+
+/// Generate a Poly type for a type with a `'de` lifetime that can't derive [`Poly`](crate::Poly),
+/// such as an alias of a generic type. Implements [`DeserializePoly`](crate::DeserializePoly) for
+/// the Poly type with `Out<'de>` as given, and [`SerializePoly`](crate::SerializePoly) for the type
+/// itself with the Poly type as `Out`. The type must not already implement `SerializePoly`, e.g. by
+/// deriving `Poly` for every instantiation. The Poly type's own name is its
+/// [`PolyMarker::TYPE_NAME`](crate::PolyMarker::TYPE_NAME).
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct GenericEvent<'a, P> {
+///     pub source: &'a str,
+///     pub payload: P,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Payload {
+///     pub id: u32,
+/// }
+///
+/// pub type Event<'a> = GenericEvent<'a, Payload>;
+///
+/// serde_poly::poly_alias!(pub EventPoly = GenericEvent<'de, Payload>);
+///
+/// let event: <EventPoly as serde_poly::DeserializePoly>::Out<'_> =
+///     serde_json::from_str(r#"{"source":"sensor","payload":{"id":1}}"#).unwrap();
+/// assert_eq!(event.source, "sensor");
+/// ```
+#[macro_export]
+macro_rules! poly_alias {
+    ($(#[$attr:meta])* $vis:vis $name:ident = $out:ty $(;)?) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name();
+
+        impl $name {
+            /// Creates the Poly type.
+            $vis const fn new() -> Self {
+                Self()
+            }
+        }
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<__S>(&self, serializer: __S) -> Result<__S::Ok, __S::Error>
+            where
+                __S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_unit_struct(stringify!($name))
+            }
+        }
+
+        impl $crate::PolyMarker for $name {
+            const TYPE_NAME: &'static str = stringify!($name);
+        }
+
+        impl $crate::DeserializePoly for $name {
+            type Out<'de> = $out;
+        }
+
+        impl<'de> $crate::SerializePoly for $out {
+            type Out = $name;
+        }
+
+        impl<'de> $crate::PolyPair for $out {
+            type Marker = $name;
+        }
+    };
+}
+
+/// Implement [`DeserializePoly`](crate::DeserializePoly) and [`SerializePoly`](crate::SerializePoly)
+/// by hand, for types that can't use the derives.
+///
+/// - `impl_poly!(A, B)` implements both traits with `Self` for owned types.
+/// - `impl_poly!(impl<T> Wrapper<T> where T: Bound)` does the same for a generic owned type, bounding
+///   the impls on the type's serde impls and any extra predicates.
+/// - `impl_poly!(pub RefPoly = Ref<'de>)` generates a Poly type for a borrowing type, as
+///   [`poly_alias!`](crate::poly_alias) does.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Celsius(pub f32);
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Meters<T>(pub T);
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Label<'a>(pub &'a str);
+///
+/// serde_poly::impl_poly!(Celsius);
+/// serde_poly::impl_poly!(impl<T> Meters<T> where T: Copy);
+/// serde_poly::impl_poly!(pub LabelPoly = Label<'de>);
+///
+/// let label: <LabelPoly as serde_poly::DeserializePoly>::Out<'_> =
+///     serde_json::from_str(r#""borrowed""#).unwrap();
+/// assert_eq!(label.0, "borrowed");
+/// ```
+#[macro_export]
+macro_rules! impl_poly {
+    (impl<$($generic:ident),+ $(,)?> $name:ty $(where $($predicate:tt)+)?) => {
+        impl<$($generic),+> $crate::DeserializePoly for $name
+        where
+            $name: for<'de> $crate::__private::serde::Deserialize<'de>,
+            $($($predicate)+)?
+        {
+            type Out<'de> = Self;
+        }
+
+        impl<$($generic),+> $crate::SerializePoly for $name
+        where
+            $name: $crate::__private::serde::Serialize,
+            $($($predicate)+)?
+        {
+            type Out = Self;
+        }
+
+        impl<$($generic),+> $crate::PolyPair for $name
+        where
+            $name: $crate::DeserializePoly,
+        {
+            type Marker = Self;
+        }
+    };
+
+    ($(#[$attr:meta])* $vis:vis $poly:ident = $out:ty $(;)?) => {
+        $crate::poly_alias!($(#[$attr])* $vis $poly = $out);
+    };
+
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl $crate::DeserializePoly for $name {
+                type Out<'de> = Self;
+            }
+
+            impl $crate::SerializePoly for $name {
+                type Out = Self;
+            }
+
+            impl $crate::PolyPair for $name {
+                type Marker = Self;
+            }
+        )+
+    };
+}
+
+/// Name the Poly type of a type in type position, `Self` for owned types and the generated Poly
+/// type for borrowing ones. Borrowing types are written with any lifetime, which doesn't change the
+/// result.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_poly::{poly, Poly};
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// pub struct Owned(pub u32);
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// pub struct Borrowed<'a>(pub &'a str);
+///
+/// let _: poly!(Owned) = Owned(1);
+/// let _: poly!(Borrowed<'static>) = BorrowedPoly::new();
+/// ```
+#[macro_export]
+macro_rules! poly {
+    ($ty:ty) => {
+        <$ty as $crate::SerializePoly>::Out
+    };
+}
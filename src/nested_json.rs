@@ -0,0 +1,188 @@
+use crate::{BorrowPoly, DeserializePoly, MakeOwned, SerializePoly};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A value stored as a JSON string nested inside its parent document, and only deserialized when
+/// read with [`NestedJson::get`]. `T` is the value's Poly type, so the blob can be read as the
+/// value borrowing from it, and the blob itself borrows from the parent document when it can.
+///
+/// Mark a field with `#[poly(nested_json)]` for typed accessors on the parent type.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_poly::{NestedJson, Poly};
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// struct Payload<'a> {
+///     id: &'a str,
+/// }
+///
+/// let blob = NestedJson::new(&Payload { id: "p1" }).unwrap();
+/// assert_eq!(blob.as_str(), r#"{"id":"p1"}"#);
+/// assert_eq!(blob.get().unwrap().id, "p1");
+/// ```
+pub struct NestedJson<'a, T>(Cow<'a, str>, PhantomData<fn() -> T>);
+
+impl<T> NestedJson<'static, T> {
+    /// Serializes `value` as a new blob.
+    pub fn new<V>(value: &V) -> Result<Self, serde_json::Error>
+    where
+        V: SerializePoly<Out = T> + ?Sized,
+    {
+        Ok(NestedJson(
+            Cow::Owned(serde_json::to_string(value)?),
+            PhantomData,
+        ))
+    }
+}
+
+impl<'a, T> NestedJson<'a, T> {
+    /// Wraps JSON that's already serialized, which isn't checked until it's read.
+    pub fn from_json(json: impl Into<Cow<'a, str>>) -> Self {
+        NestedJson(json.into(), PhantomData)
+    }
+
+    /// The serialized JSON.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Replaces the blob with `value` serialized.
+    pub fn set<V>(&mut self, value: &V) -> Result<(), serde_json::Error>
+    where
+        V: SerializePoly<Out = T> + ?Sized,
+    {
+        self.0 = Cow::Owned(serde_json::to_string(value)?);
+        Ok(())
+    }
+
+    /// Converts the blob to one that owns its JSON.
+    pub fn into_owned(self) -> NestedJson<'static, T> {
+        NestedJson(Cow::Owned(self.0.into_owned()), PhantomData)
+    }
+}
+
+impl<T> MakeOwned for NestedJson<'_, T> {
+    fn make_owned(&mut self) {
+        self.0.make_owned();
+    }
+}
+
+impl<T> BorrowPoly for NestedJson<'_, T> {
+    type Borrowed<'a>
+        = NestedJson<'a, T>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> NestedJson<'_, T> {
+        NestedJson(self.0.reborrow(), PhantomData)
+    }
+}
+
+impl<T: DeserializePoly> NestedJson<'_, T> {
+    /// Deserializes the value, borrowing from the blob.
+    pub fn get(&self) -> Result<T::Out<'_>, serde_json::Error> {
+        serde_json::from_str(&self.0)
+    }
+
+    /// Deserializes the value into `place`, reusing what it already holds where its `Deserialize`
+    /// impl supports it, such as a `Vec`'s buffer, when the same blob type is read repeatedly. On
+    /// error, `place` is left in a valid but unspecified state.
+    pub fn get_in_place<'s>(&'s self, place: &mut T::Out<'s>) -> Result<(), serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_str(&self.0);
+        Deserialize::deserialize_in_place(&mut deserializer, place)?;
+        deserializer.end()
+    }
+
+    /// Deserializes the value like [`get`](Self::get), but a failure keeps the JSON that couldn't
+    /// be read alongside the error, e.g. to dead-letter it.
+    pub fn get_or_raw(&self) -> Result<T::Out<'_>, DecodeFailure<'_>> {
+        self.get().map_err(|error| DecodeFailure {
+            error,
+            raw: &self.0,
+        })
+    }
+}
+
+/// A [`NestedJson`] blob that failed to deserialize, from [`NestedJson::get_or_raw`].
+#[derive(Debug)]
+pub struct DecodeFailure<'a> {
+    pub error: serde_json::Error,
+    /// The blob's JSON, as it was when read.
+    pub raw: &'a str,
+}
+
+impl fmt::Display for DecodeFailure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to read nested JSON: {}", self.error)
+    }
+}
+
+impl std::error::Error for DecodeFailure<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<T> Serialize for NestedJson<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de: 'a, 'a, T> Deserialize<'de> for NestedJson<'a, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(NestedJsonVisitor(PhantomData))
+    }
+}
+
+struct NestedJsonVisitor<'a, T>(PhantomData<NestedJson<'a, T>>);
+
+impl<'de: 'a, 'a, T> Visitor<'de> for NestedJsonVisitor<'a, T> {
+    type Value = NestedJson<'a, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string of nested JSON")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, json: &'de str) -> Result<Self::Value, E> {
+        Ok(NestedJson::from_json(json))
+    }
+
+    fn visit_str<E: de::Error>(self, json: &str) -> Result<Self::Value, E> {
+        Ok(NestedJson::from_json(json.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, json: String) -> Result<Self::Value, E> {
+        Ok(NestedJson::from_json(json))
+    }
+}
+
+impl<T> Clone for NestedJson<'_, T> {
+    fn clone(&self) -> Self {
+        NestedJson(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T> fmt::Debug for NestedJson<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NestedJson").field(&self.0).finish()
+    }
+}
+
+impl<T> PartialEq for NestedJson<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for NestedJson<'_, T> {}
@@ -0,0 +1,92 @@
+//! Support items for the derive macros, not public API.
+
+use serde::{Deserialize, Serialize};
+
+/// Asserted by `#[derive(Poly)]` so a missing `Deserialize` impl is reported on the source type,
+/// and bounding its `DeserializePoly` impl, which the supertrait satisfies `Out<'de>` for.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Deserialize<'de>` to derive DeserializePoly",
+    label = "missing `#[derive(Deserialize)]`?"
+)]
+pub trait PolyDeserialize<'de>: Deserialize<'de> {}
+
+impl<'de, T: Deserialize<'de>> PolyDeserialize<'de> for T {}
+
+/// Asserted by `#[derive(Poly)]` so a missing `Serialize` impl is reported on the source type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` must implement `Serialize` to derive SerializePoly",
+    label = "missing `#[derive(Serialize)]`?"
+)]
+pub trait PolySerialize {}
+
+impl<T: Serialize> PolySerialize for T {}
+
+pub const fn assert_deserialize<'de, T: PolyDeserialize<'de>>() {}
+
+pub const fn assert_deserialize_owned<T: for<'de> PolyDeserialize<'de>>() {}
+
+pub const fn assert_serialize<T: PolySerialize>() {}
+
+pub use serde;
+#[cfg(feature = "serde_json")]
+pub use serde_json;
+
+/// The [`OwnablePoly`](crate::OwnablePoly) hooks of a type that is its own owned type, returning
+/// the pointer they're given so its allocation, or its sharing, is kept. Expanded within the impl
+/// by the derive, `poly_bitflags!` and the crate's own identity impls.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_ownable_identity {
+    () => {
+        fn __into_owned_box(boxed: ::std::boxed::Box<Self>) -> ::std::boxed::Box<Self> {
+            boxed
+        }
+
+        fn __into_owned_arc(arc: ::std::sync::Arc<Self>) -> ::std::sync::Arc<Self> {
+            arc
+        }
+
+        fn __into_owned_rc(rc: ::std::rc::Rc<Self>) -> ::std::rc::Rc<Self> {
+            rc
+        }
+    };
+}
+
+/// The `#[poly(test)]` round-trip module for `$ty`, going through the crate's own `serde_json` so
+/// the deriving crate doesn't need it as a dependency.
+#[cfg(feature = "serde_json")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_round_trip_test {
+    ($module:ident, $ty:ty) => {
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn round_trip() {
+                type Marker = <$ty as $crate::SerializePoly>::Out;
+
+                let value: $ty = ::core::default::Default::default();
+                let json = $crate::__private::serde_json::to_string(&value).unwrap();
+                let borrowed: <Marker as $crate::DeserializePoly>::Out<'_> =
+                    $crate::__private::serde_json::from_str(&json).unwrap();
+                let owned: $ty = $crate::OwnablePoly::into_owned(borrowed);
+                assert_eq!(owned, value);
+            }
+        }
+    };
+}
+
+/// Without `serde_json` there's nothing to round-trip through, so `#[poly(test)]` is an error.
+#[cfg(not(feature = "serde_json"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_round_trip_test {
+    ($module:ident, $ty:ty) => {
+        ::core::compile_error!(
+            "poly(test) round-trips through JSON and needs serde-poly's `serde_json` feature"
+        );
+    };
+}
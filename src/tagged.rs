@@ -0,0 +1,243 @@
+use crate::content::{Content, ContentDeserializer};
+use crate::{BorrowPoly, DeserializePoly, MakeOwned, OwnablePoly, PolyTag, SerializePoly};
+use serde::de::{self, DeserializeSeed, Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+/// A value wrapped in a `{ "type": TAG, "data": value }` envelope, with the tag from
+/// `#[poly(tag = "...")]`. Deserializing checks the tag before the data, buffering data that comes
+/// first as it does from a `serde_json::Value` or Postgres `jsonb`, so reading the wrong blob type
+/// fails with an error naming both tags rather than a confusing field error.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_poly::{Poly, Tagged};
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// #[poly(tag = "app.Event")]
+/// struct Event<'a> {
+///     name: &'a str,
+/// }
+///
+/// let json = serde_json::to_string(&Tagged(Event { name: "start" })).unwrap();
+/// assert_eq!(json, r#"{"type":"app.Event","data":{"name":"start"}}"#);
+/// let event: Tagged<Event<'_>> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(event.name, "start");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tagged<T>(pub T);
+
+impl<T: Serialize + PolyTag> Serialize for Tagged<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut envelope = serializer.serialize_struct("Tagged", 2)?;
+        envelope.serialize_field("type", T::TAG)?;
+        envelope.serialize_field("data", &self.0)?;
+        envelope.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + PolyTag> Deserialize<'de> for Tagged<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = TaggedVisitor {
+            human_readable: deserializer.is_human_readable(),
+            marker: PhantomData,
+        };
+        deserializer.deserialize_struct("Tagged", &["type", "data"], visitor)
+    }
+}
+
+/// `human_readable` is kept for replaying data buffered ahead of the tag.
+struct TaggedVisitor<T> {
+    human_readable: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de> + PolyTag> Visitor<'de> for TaggedVisitor<T> {
+    type Value = Tagged<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a `{}` envelope", T::TAG)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        seq.next_element_seed(Tag(T::TAG))?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let data = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(Tagged(data))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut tagged = false;
+        let mut data = None;
+        // Data ahead of the tag, as from a sorted map, is buffered until the tag is checked, so a
+        // mismatch isn't reported as an error in the data.
+        let mut buffered = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Type if tagged => return Err(A::Error::duplicate_field("type")),
+                Field::Type => {
+                    map.next_value_seed(Tag(T::TAG))?;
+                    tagged = true;
+                }
+                Field::Data if data.is_some() || buffered.is_some() => {
+                    return Err(A::Error::duplicate_field("data"));
+                }
+                Field::Data if !tagged => buffered = Some(map.next_value::<Content<'de>>()?),
+                Field::Data => data = Some(map.next_value()?),
+                Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        if !tagged {
+            return Err(A::Error::missing_field("type"));
+        }
+        match (data, buffered) {
+            (Some(data), _) => Ok(Tagged(data)),
+            (None, Some(content)) => {
+                T::deserialize(ContentDeserializer::new(content, self.human_readable)).map(Tagged)
+            }
+            (None, None) => Err(A::Error::missing_field("data")),
+        }
+    }
+}
+
+enum Field {
+    Type,
+    Data,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("`type` or `data`")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<Field, E> {
+        Ok(match index {
+            0 => Field::Type,
+            1 => Field::Data,
+            _ => Field::Other,
+        })
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Field, E> {
+        Ok(match name {
+            "type" => Field::Type,
+            "data" => Field::Data,
+            _ => Field::Other,
+        })
+    }
+}
+
+/// Deserializes a tag, failing unless it's the expected one.
+struct Tag(&'static str);
+
+impl<'de> DeserializeSeed<'de> for Tag {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for Tag {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the tag `{}`", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, tag: &str) -> Result<(), E> {
+        match tag == self.0 {
+            true => Ok(()),
+            false => Err(E::custom(format_args!(
+                "expected a `{}` blob, found a `{}` blob",
+                self.0, tag
+            ))),
+        }
+    }
+}
+
+impl<T: SerializePoly + PolyTag> SerializePoly for Tagged<T> {
+    type Out = Tagged<T::Out>;
+}
+
+impl<P: DeserializePoly> DeserializePoly for Tagged<P>
+where
+    for<'de> P::Out<'de>: PolyTag,
+{
+    type Out<'de> = Tagged<P::Out<'de>>;
+}
+
+impl<T: OwnablePoly> OwnablePoly for Tagged<T> {
+    type Owned = Tagged<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Tagged(self.0.into_owned())
+    }
+}
+
+impl<T: MakeOwned> MakeOwned for Tagged<T> {
+    fn make_owned(&mut self) {
+        self.0.make_owned();
+    }
+}
+
+impl<T: BorrowPoly> BorrowPoly for Tagged<T> {
+    type Borrowed<'a>
+        = Tagged<T::Borrowed<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_> {
+        Tagged(self.0.reborrow())
+    }
+}
+
+impl<T> Deref for Tagged<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Tagged<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
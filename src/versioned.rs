@@ -0,0 +1,252 @@
+use crate::content::{Content, ContentDeserializer};
+use crate::{
+    BorrowPoly, DeserializePoly, DeserializeVersion, MakeOwned, OwnablePoly, PolyTag, PolyVersion,
+    SerializePoly,
+};
+use serde::de::{self, DeserializeSeed, Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+/// A value wrapped in a `{ "version": N, "data": value }` envelope, with the version from
+/// `#[poly(version = N)]`. Older versions are deserialized as their own type and upgraded through
+/// the [`Upgrade`](crate::Upgrade) chain declared with `#[poly(upgrade_from = "...")]`. Data that
+/// comes before the version, as it does from a `serde_json::Value` or Postgres `jsonb`, is buffered
+/// until the version is known.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_poly::{Poly, Upgrade, Versioned};
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// #[poly(version = 1)]
+/// struct UserV1<'a> {
+///     name: &'a str,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Poly)]
+/// #[poly(version = 2, upgrade_from = "UserV1<'a>")]
+/// struct User<'a> {
+///     name: &'a str,
+///     admin: bool,
+/// }
+///
+/// impl<'a> Upgrade<UserV1<'a>> for User<'a> {
+///     fn upgrade(from: UserV1<'a>) -> Self {
+///         User { name: from.name, admin: false }
+///     }
+/// }
+///
+/// let json = r#"{"version":1,"data":{"name":"ada"}}"#;
+/// let user: Versioned<User<'_>> = serde_json::from_str(json).unwrap();
+/// assert_eq!((user.name, user.admin), ("ada", false));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Versioned<T>(pub T);
+
+impl<T: Serialize + PolyVersion> Serialize for Versioned<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut envelope = serializer.serialize_struct("Versioned", 2)?;
+        envelope.serialize_field("version", &T::VERSION)?;
+        envelope.serialize_field("data", &self.0)?;
+        envelope.end()
+    }
+}
+
+impl<'de, T: DeserializeVersion<'de>> Deserialize<'de> for Versioned<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = VersionedVisitor {
+            human_readable: deserializer.is_human_readable(),
+            marker: PhantomData,
+        };
+        deserializer.deserialize_struct("Versioned", &["version", "data"], visitor)
+    }
+}
+
+/// `human_readable` is kept for replaying data buffered ahead of the version.
+struct VersionedVisitor<T> {
+    human_readable: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: DeserializeVersion<'de>> Visitor<'de> for VersionedVisitor<T> {
+    type Value = Versioned<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a versioned envelope")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let data = seq
+            .next_element_seed(Version(version, PhantomData))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(Versioned(data))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut version = None;
+        let mut data = None;
+        // The data's type depends on the version, so data ahead of it, as from a sorted map, is
+        // buffered until the version is known.
+        let mut buffered = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version if version.is_some() => {
+                    return Err(A::Error::duplicate_field("version"));
+                }
+                Field::Version => version = Some(map.next_value()?),
+                Field::Data if data.is_some() || buffered.is_some() => {
+                    return Err(A::Error::duplicate_field("data"));
+                }
+                Field::Data => match version {
+                    Some(version) => {
+                        data = Some(map.next_value_seed(Version(version, PhantomData))?)
+                    }
+                    None => buffered = Some(map.next_value::<Content<'de>>()?),
+                },
+                Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let Some(version) = version else {
+            return Err(A::Error::missing_field("version"));
+        };
+        match (data, buffered) {
+            (Some(data), _) => Ok(Versioned(data)),
+            (None, Some(content)) => T::deserialize_version(
+                version,
+                ContentDeserializer::new(content, self.human_readable),
+            )
+            .map(Versioned),
+            (None, None) => Err(A::Error::missing_field("data")),
+        }
+    }
+}
+
+enum Field {
+    Version,
+    Data,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("`version` or `data`")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<Field, E> {
+        Ok(match index {
+            0 => Field::Version,
+            1 => Field::Data,
+            _ => Field::Other,
+        })
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Field, E> {
+        Ok(match name {
+            "version" => Field::Version,
+            "data" => Field::Data,
+            _ => Field::Other,
+        })
+    }
+}
+
+/// Deserializes the data of the given version.
+struct Version<T>(u32, PhantomData<T>);
+
+impl<'de, T: DeserializeVersion<'de>> DeserializeSeed<'de> for Version<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_version(self.0, deserializer)
+    }
+}
+
+impl<T: SerializePoly + PolyVersion> SerializePoly for Versioned<T> {
+    type Out = Versioned<T::Out>;
+}
+
+impl<P: DeserializePoly> DeserializePoly for Versioned<P>
+where
+    for<'de> P::Out<'de>: DeserializeVersion<'de>,
+{
+    type Out<'de> = Versioned<P::Out<'de>>;
+}
+
+impl<T: OwnablePoly> OwnablePoly for Versioned<T> {
+    type Owned = Versioned<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Versioned(self.0.into_owned())
+    }
+}
+
+impl<T: MakeOwned> MakeOwned for Versioned<T> {
+    fn make_owned(&mut self) {
+        self.0.make_owned();
+    }
+}
+
+impl<T: BorrowPoly> BorrowPoly for Versioned<T> {
+    type Borrowed<'a>
+        = Versioned<T::Borrowed<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow(&self) -> Self::Borrowed<'_> {
+        Versioned(self.0.reborrow())
+    }
+}
+
+/// A versioned type keeps its tag, so it can be nested as `Tagged<Versioned<T>>`.
+impl<T: PolyTag> PolyTag for Versioned<T> {
+    const TAG: &'static str = T::TAG;
+}
+
+impl<T> Deref for Versioned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Versioned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
@@ -0,0 +1,65 @@
+//! Adapters for use with `#[serde(with = "...")]`, storing a field as a nested serialized blob
+//! inside the parent document without changing the field's Rust type.
+
+/// Store a field as a JSON string nested inside the parent document.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payload {
+///     id: u32,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "serde_poly::with::json")]
+///     payload: Payload,
+/// }
+///
+/// let row = Row { payload: Payload { id: 1 } };
+/// let json = serde_json::to_string(&row).unwrap();
+/// assert_eq!(json, r#"{"payload":"{\"id\":1}"}"#);
+/// ```
+///
+/// Nested JSON is escaped inside its parent string, so the inner value can never borrow from the
+/// input and must implement [`serde::de::DeserializeOwned`].
+pub mod json {
+    use serde::de::{self, DeserializeOwned, Visitor};
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        let json = serde_json::to_string(value).map_err(S::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(JsonVisitor(PhantomData))
+    }
+
+    /// Parses the nested JSON where the deserializer hands it over, without copying the string.
+    struct JsonVisitor<T>(PhantomData<fn() -> T>);
+
+    impl<T: DeserializeOwned> Visitor<'_> for JsonVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string of nested JSON")
+        }
+
+        fn visit_str<E: de::Error>(self, json: &str) -> Result<Self::Value, E> {
+            serde_json::from_str(json).map_err(E::custom)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "chrono-tz")]
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, OwnablePoly, Poly, SerializePoly};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+struct Schedule<'a> {
+    name: &'a str,
+    at: DateTime<Utc>,
+    zone: chrono_tz::Tz,
+}
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+    serde_json::from_str(json).unwrap()
+}
+
+fn serialize<T: SerializePoly>(item: &T) -> String {
+    serde_json::to_string(item).unwrap()
+}
+
+#[test]
+fn chrono_fields_round_trip() {
+    let schedule = Schedule {
+        name: "standup",
+        at: Utc.with_ymd_and_hms(2024, 1, 2, 9, 30, 0).unwrap(),
+        zone: chrono_tz::Europe::London,
+    };
+    let json = serialize(&schedule);
+
+    let back = deserialize::<SchedulePoly>(&json);
+    assert_eq!(back, schedule);
+}
+
+#[test]
+fn zoned_datetimes_are_serialize_poly() {
+    let at = chrono_tz::Europe::London
+        .with_ymd_and_hms(2024, 1, 2, 9, 30, 0)
+        .unwrap();
+    assert_eq!(serialize(&at), r#""2024-01-02T09:30:00Z""#);
+    assert_eq!(at.into_owned(), at);
+}
@@ -0,0 +1,25 @@
+use serde::de::{Deserializer, Visitor};
+
+/// Reads a self-describing format as a compact one would, by reporting that it isn't
+/// human-readable, for types such as `IpAddr` that read differently in each.
+pub struct Compact<D>(pub D);
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Compact<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
@@ -1,4 +1,4 @@
-use serde_poly::{OwnablePoly};
+use serde_poly::{BorrowPoly, IntoStatic, MakeOwned, OwnablePoly, OwnedOf};
 use std::borrow::Cow;
 
 #[derive(OwnablePoly)]
@@ -37,22 +37,23 @@ enum SimpleEnum<'a> {
 
 #[derive(OwnablePoly, Debug, PartialEq)]
 enum ComplexEnum<'a> {
-    Named {
-        name: Cow<'a, str>,
-        count: u32
-    },
+    Named { name: Cow<'a, str>, count: u32 },
     Tuple(Cow<'a, str>, u32, String),
     Unit,
 }
 
 #[derive(OwnablePoly, Debug, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 enum MixedEnum<'a, T> {
     WithLifetime(Cow<'a, str>),
     WithGeneric(T),
-    WithBoth {
-        data: Cow<'a, str>,
-        value: T
-    },
+    WithBoth { data: Cow<'a, str>, value: T },
+}
+
+#[derive(OwnablePoly, Debug, Clone, PartialEq)]
+struct Cloneable<'a> {
+    name: Cow<'a, str>,
+    tags: Vec<Cow<'a, str>>,
 }
 
 #[derive(OwnablePoly, Debug, PartialEq)]
@@ -148,25 +149,23 @@ fn test_complex_enum_named() {
         count: 42,
     };
     let owned: ComplexEnum<'static> = example.into_owned();
-    assert_eq!(owned, ComplexEnum::Named {
-        name: Cow::Owned("test".to_string()),
-        count: 42,
-    });
+    assert_eq!(
+        owned,
+        ComplexEnum::Named {
+            name: Cow::Owned("test".to_string()),
+            count: 42,
+        }
+    );
 }
 
 #[test]
 fn test_complex_enum_tuple() {
-    let example = ComplexEnum::Tuple(
-        Cow::Borrowed("hello"),
-        123,
-        "world".to_string(),
-    );
+    let example = ComplexEnum::Tuple(Cow::Borrowed("hello"), 123, "world".to_string());
     let owned: ComplexEnum<'static> = example.into_owned();
-    assert_eq!(owned, ComplexEnum::Tuple(
-        Cow::Owned("hello".to_string()),
-        123,
-        "world".to_string(),
-    ));
+    assert_eq!(
+        owned,
+        ComplexEnum::Tuple(Cow::Owned("hello".to_string()), 123, "world".to_string(),)
+    );
 }
 
 #[test]
@@ -180,7 +179,10 @@ fn test_complex_enum_unit() {
 fn test_mixed_enum_with_lifetime() {
     let example: MixedEnum<'_, String> = MixedEnum::WithLifetime(Cow::Borrowed("test"));
     let owned: MixedEnum<'static, String> = example.into_owned();
-    assert_eq!(owned, MixedEnum::WithLifetime(Cow::Owned("test".to_string())));
+    assert_eq!(
+        owned,
+        MixedEnum::WithLifetime(Cow::Owned("test".to_string()))
+    );
 }
 
 #[test]
@@ -197,10 +199,13 @@ fn test_mixed_enum_with_both() {
         value: "world".to_string(),
     };
     let owned: MixedEnum<'static, String> = example.into_owned();
-    assert_eq!(owned, MixedEnum::WithBoth {
-        data: Cow::Owned("hello".to_string()),
-        value: "world".to_string(),
-    });
+    assert_eq!(
+        owned,
+        MixedEnum::WithBoth {
+            data: Cow::Owned("hello".to_string()),
+            value: "world".to_string(),
+        }
+    );
 }
 
 #[test]
@@ -217,3 +222,666 @@ fn test_no_lifetime_enum() {
     let owned = example.into_owned();
     assert_eq!(owned, NoLifetimeEnum::Variant3);
 }
+
+#[test]
+fn test_once_cells() {
+    let cell = std::cell::OnceCell::from(Cow::Borrowed("cell"));
+    let owned: std::cell::OnceCell<Cow<'static, str>> = cell.into_owned();
+    assert_eq!(owned.get().map(|s| s.as_ref()), Some("cell"));
+
+    let lock: std::sync::OnceLock<Cow<'_, str>> = std::sync::OnceLock::new();
+    let owned: std::sync::OnceLock<Cow<'static, str>> = lock.into_owned();
+    assert!(owned.get().is_none());
+}
+
+#[test]
+fn test_to_owned_poly_keeps_the_original() {
+    let text = String::from("name tag");
+    let borrowed = Cloneable {
+        name: Cow::Borrowed(&text[..4]),
+        tags: vec![Cow::Borrowed(&text[5..])],
+    };
+    let owned: Cloneable<'static> = borrowed.to_owned_poly();
+    assert!(matches!(owned.name, Cow::Owned(_)));
+    assert!(matches!(owned.tags[0], Cow::Owned(_)));
+    assert_eq!(owned, borrowed);
+    assert!(matches!(borrowed.name, Cow::Borrowed("name")));
+
+    let strings: Vec<Cow<'static, str>> = vec![Cow::Borrowed("a")].to_owned_poly();
+    assert!(matches!(strings[0], Cow::Owned(_)));
+}
+
+#[derive(MakeOwned, Debug, PartialEq)]
+struct Document<'a> {
+    title: Cow<'a, str>,
+    sections: Vec<Cow<'a, str>>,
+    summary: Option<Cow<'a, str>>,
+    pages: u32,
+}
+
+#[derive(MakeOwned, Debug, PartialEq)]
+enum Change<'a, T: Clone> {
+    Rename(Cow<'a, str>),
+    Reorder { items: Cow<'a, [T]> },
+    Clear,
+}
+
+#[test]
+fn test_make_owned_in_place() {
+    let text = String::from("title intro body");
+    let mut document = Document {
+        title: Cow::Borrowed(&text[..5]),
+        sections: vec![Cow::Borrowed(&text[6..11]), Cow::Owned("notes".to_string())],
+        summary: Some(Cow::Borrowed(&text[12..])),
+        pages: 3,
+    };
+    document.make_owned();
+    assert!(matches!(document.title, Cow::Owned(_)));
+    assert!(document.sections.iter().all(|s| matches!(s, Cow::Owned(_))));
+    assert!(matches!(document.summary, Some(Cow::Owned(_))));
+    assert_eq!(document.title, "title");
+    assert_eq!(document.sections, ["intro", "notes"]);
+    assert_eq!(document.pages, 3);
+
+    let items = [1, 2, 3];
+    let mut change = Change::Reorder {
+        items: Cow::Borrowed(&items[..]),
+    };
+    change.make_owned();
+    assert!(matches!(
+        change,
+        Change::Reorder {
+            items: Cow::Owned(_)
+        }
+    ));
+    let mut rename = Change::<u8>::Rename(Cow::Borrowed(&text[..5]));
+    rename.make_owned();
+    assert!(matches!(rename, Change::Rename(Cow::Owned(_))));
+    let mut clear = Change::<u8>::Clear;
+    clear.make_owned();
+    assert_eq!(clear, Change::Clear);
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+struct Configured<'a, C> {
+    name: Cow<'a, str>,
+    #[ownable(clone)]
+    config: C,
+}
+
+#[test]
+fn test_ownable_clone_moves_the_field_through() {
+    let text = String::from("job");
+    let configured = Configured {
+        name: Cow::Borrowed(&text[..]),
+        config: std::sync::Arc::new(vec![1, 2]),
+    };
+    let owned: Configured<'static, std::sync::Arc<Vec<u8>>> = configured.into_owned();
+    assert_eq!(owned.name, "job");
+    assert_eq!(*owned.config, [1, 2]);
+}
+
+struct Config;
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+struct WithPhantom<'a, T> {
+    name: Cow<'a, str>,
+    #[ownable(skip)]
+    config: std::marker::PhantomData<&'a Config>,
+    #[ownable(skip = "Vec::new()")]
+    cache: Vec<&'a T>,
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+enum Lookup<'a> {
+    Hit(Cow<'a, str>, #[ownable(skip = "None")] Option<&'a str>),
+    Miss {
+        key: Cow<'a, str>,
+        #[ownable(skip)]
+        source: Option<&'a str>,
+    },
+}
+
+#[test]
+fn test_ownable_skip_replaces_the_field() {
+    let text = String::from("name");
+    let values = [1, 2];
+    let value = WithPhantom {
+        name: Cow::Borrowed(&text[..]),
+        config: std::marker::PhantomData,
+        cache: values.iter().collect(),
+    };
+    let owned: WithPhantom<'static, i32> = value.into_owned();
+    assert_eq!(owned.name, "name");
+    assert!(owned.cache.is_empty());
+
+    let hit = Lookup::Hit(Cow::Borrowed(&text[..]), Some(&text[..]));
+    assert_eq!(hit.into_owned(), Lookup::Hit(Cow::Borrowed("name"), None));
+    let miss = Lookup::Miss {
+        key: Cow::Borrowed(&text[..]),
+        source: Some(&text[..]),
+    };
+    assert_eq!(
+        miss.into_owned(),
+        Lookup::Miss {
+            key: Cow::Borrowed("name"),
+            source: None,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct Labelled<'a, T> {
+    label: Cow<'a, str>,
+    value: T,
+}
+
+impl<T: OwnablePoly> OwnablePoly for Labelled<'_, T> {
+    type Owned = Labelled<'static, T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Labelled {
+            label: OwnablePoly::into_owned(self.label),
+            value: self.value.into_owned(),
+        }
+    }
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+#[ownable(bound = "T: OwnablePoly<Owned = T>")]
+struct Labels<'a, T> {
+    labels: Vec<Labelled<'a, T>>,
+}
+
+#[test]
+fn test_ownable_bound_is_added_to_the_impl() {
+    let text = String::from("size");
+    let labels = Labels {
+        labels: vec![Labelled {
+            label: Cow::Borrowed(&text[..]),
+            value: 3u32,
+        }],
+    };
+    let owned: Labels<'static, u32> = labels.into_owned();
+    assert_eq!(owned.labels[0].label, "size");
+    assert_eq!(owned.labels[0].value, 3);
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+struct Generic<'a, T> {
+    name: Cow<'a, str>,
+    value: T,
+    values: Vec<T>,
+}
+
+struct NotOwnable;
+
+#[derive(OwnablePoly)]
+#[ownable(no_bound)]
+struct Unbounded<'a, T> {
+    name: Cow<'a, str>,
+    value: T,
+}
+
+#[test]
+fn test_generic_fields_are_bounded_and_converted() {
+    let text = String::from("name");
+    let generic = Generic {
+        name: Cow::Borrowed(&text[..]),
+        value: Cow::Borrowed("value"),
+        values: vec![Cow::Borrowed("a")],
+    };
+    let owned: Generic<'static, Cow<'static, str>> = generic.into_owned();
+    assert!(matches!(owned.value, Cow::Owned(_)));
+    assert!(matches!(owned.values[0], Cow::Owned(_)));
+
+    let unbounded = Unbounded {
+        name: Cow::Borrowed(&text[..]),
+        value: NotOwnable,
+    };
+    let owned: Unbounded<'static, NotOwnable> = unbounded.into_owned();
+    assert_eq!(owned.name, "name");
+    let NotOwnable = owned.value;
+}
+
+#[derive(BorrowPoly, OwnablePoly, Debug, PartialEq)]
+struct Article<'a> {
+    title: Cow<'a, str>,
+    tags: Vec<Cow<'a, str>>,
+    views: u64,
+}
+
+#[derive(BorrowPoly, Debug, PartialEq)]
+enum Block<'a, T: Clone> {
+    Text(Cow<'a, str>),
+    Data { rows: Cow<'a, [T]>, width: T },
+    Break,
+}
+
+#[test]
+fn test_borrow_poly_reborrows_owned_data() {
+    let text = String::from("title");
+    let article = Article {
+        title: Cow::Borrowed(&text[..]),
+        tags: vec![Cow::Borrowed("a")],
+        views: 7,
+    };
+    let owned: Article<'static> = article.into_owned();
+    let borrowed = owned.reborrow();
+    assert!(matches!(borrowed.title, Cow::Borrowed("title")));
+    assert!(matches!(borrowed.tags[0], Cow::Borrowed("a")));
+    assert_eq!(borrowed, owned);
+    let owned_again: Article<'static> = borrowed.into_owned();
+    assert_eq!(owned_again, owned);
+
+    let data: Block<'static, u8> = Block::Data {
+        rows: Cow::Owned(vec![1, 2]),
+        width: 2,
+    };
+    assert!(matches!(
+        data.reborrow(),
+        Block::Data {
+            rows: Cow::Borrowed([1, 2]),
+            width: 2
+        }
+    ));
+    assert_eq!(
+        Block::<u8>::Text(Cow::Borrowed("t")).reborrow(),
+        Block::Text(Cow::Borrowed("t"))
+    );
+    assert_eq!(Block::<u8>::Break.reborrow(), Block::Break);
+}
+
+trait Schema<'s> {
+    const NAME: &'static str;
+}
+
+impl Schema<'_> for u8 {
+    const NAME: &'static str = "u8";
+}
+
+#[derive(OwnablePoly, MakeOwned, BorrowPoly, Debug, PartialEq)]
+struct Spanning<'a, 'b: 'a, T: Clone + 'b, S>
+where
+    T: 'a,
+    for<'x> S: Schema<'x>,
+{
+    short: Cow<'a, str>,
+    long: Vec<Cow<'b, [T]>>,
+    schema: std::marker::PhantomData<S>,
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+enum Spanned<'a, 'b: 'a> {
+    Short(Cow<'a, str>),
+    Long {
+        text: Cow<'b, str>,
+        short: Option<Cow<'a, str>>,
+    },
+}
+
+#[test]
+fn test_multiple_lifetimes_with_bounds() {
+    let long = [1u8, 2];
+    let text = String::from("short");
+    let mut spanning: Spanning<'_, '_, u8, u8> = Spanning {
+        short: Cow::Borrowed(&text[..]),
+        long: vec![Cow::Borrowed(&long[..])],
+        schema: std::marker::PhantomData,
+    };
+    assert!(matches!(spanning.reborrow().long[0], Cow::Borrowed([1, 2])));
+    spanning.make_owned();
+    let owned: Spanning<'static, 'static, u8, u8> = spanning.into_owned();
+    assert_eq!(owned.short, "short");
+    assert_eq!(<u8 as Schema<'static>>::NAME, "u8");
+
+    let spanned = Spanned::Long {
+        text: Cow::Borrowed(&text[..]),
+        short: Some(Cow::Borrowed(&text[..1])),
+    };
+    let owned: Spanned<'static, 'static> = spanned.into_owned();
+    assert_eq!(
+        owned,
+        Spanned::Long {
+            text: Cow::Borrowed("short"),
+            short: Some(Cow::Borrowed("s")),
+        }
+    );
+    let short = Spanned::Short(Cow::Borrowed(&text[..]));
+    assert_eq!(short.into_owned(), Spanned::Short(Cow::Borrowed("short")));
+}
+
+#[test]
+fn test_owned_vecs_keep_their_buffers() {
+    let strings = vec![String::from("a"), String::from("b")];
+    let ptr = strings.as_ptr();
+    let owned = strings.into_owned();
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let examples = vec![NoLifetimeExample {
+        data: String::from("one"),
+        count: 1,
+    }];
+    let ptr = examples.as_ptr();
+    let owned = examples.into_owned();
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let nested = vec![vec![1u32, 2], vec![3]];
+    let inner = nested[0].as_ptr();
+    let owned = nested.into_owned();
+    assert_eq!(owned[0].as_ptr(), inner);
+
+    let queue: std::collections::VecDeque<String> = vec![String::from("a")].into();
+    let ptr = queue.as_slices().0.as_ptr();
+    let owned = queue.into_owned();
+    assert_eq!(owned.as_slices().0.as_ptr(), ptr);
+
+    let cows: Vec<Cow<'_, str>> = vec![Cow::Borrowed("a")];
+    let ptr = cows.as_ptr() as *const u8;
+    let owned = cows.into_owned();
+    assert!(matches!(owned[0], Cow::Owned(_)));
+    assert_eq!(owned.as_ptr() as *const u8, ptr);
+}
+
+#[derive(OwnablePoly, Debug, PartialEq)]
+struct Payload<'a> {
+    fields: std::collections::HashMap<Cow<'a, str>, SimpleEnum<'a>>,
+    counts: std::collections::BTreeMap<Cow<'a, str>, u32>,
+    tags: std::collections::HashSet<Cow<'a, str>>,
+    sorted: std::collections::BTreeSet<Cow<'a, str>>,
+}
+
+#[test]
+fn test_maps_and_sets_convert_their_contents() {
+    let text = String::from("key text");
+    let payload = Payload {
+        fields: [(
+            Cow::Borrowed(&text[..3]),
+            SimpleEnum::Borrowed(Cow::Borrowed(&text[4..])),
+        )]
+        .into(),
+        counts: [(Cow::Borrowed(&text[..3]), 1)].into(),
+        tags: [Cow::Borrowed(&text[..3]), Cow::Borrowed(&text[4..])].into(),
+        sorted: [Cow::Borrowed(&text[4..])].into(),
+    };
+    let owned: Payload<'static> = payload.into_owned();
+    drop(text);
+    let (key, value) = owned.fields.iter().next().unwrap();
+    assert!(matches!(key, Cow::Owned(k) if k == "key"));
+    assert_eq!(*value, SimpleEnum::Borrowed(Cow::Borrowed("text")));
+    assert!(matches!(owned.counts.keys().next(), Some(Cow::Owned(_))));
+    assert_eq!(owned.counts["key"], 1);
+    assert!(owned.tags.iter().all(|tag| matches!(tag, Cow::Owned(_))));
+    assert!(owned.tags.contains("text"));
+    assert!(matches!(owned.sorted.first(), Some(Cow::Owned(t)) if t == "text"));
+}
+
+#[derive(OwnablePoly, Debug)]
+struct EventBuffer<'a> {
+    pending: std::collections::VecDeque<Cow<'a, str>>,
+    history: std::collections::LinkedList<Cow<'a, str>>,
+    priorities: std::collections::BinaryHeap<Cow<'a, str>>,
+}
+
+#[test]
+fn test_sequences_convert_their_elements() {
+    let text = String::from("a b c");
+    let buffer = EventBuffer {
+        pending: [Cow::Borrowed(&text[..1]), Cow::Borrowed(&text[2..3])].into(),
+        history: [Cow::Borrowed(&text[4..])].into(),
+        priorities: [Cow::Borrowed(&text[..1]), Cow::Borrowed(&text[4..])].into(),
+    };
+    let owned: EventBuffer<'static> = buffer.into_owned();
+    drop(text);
+    assert_eq!(owned.pending, ["a", "b"]);
+    assert!(owned.pending.iter().all(|e| matches!(e, Cow::Owned(_))));
+    assert!(matches!(owned.history.front(), Some(Cow::Owned(e)) if e == "c"));
+    assert_eq!(owned.priorities.peek().map(|e| &**e), Some("c"));
+    assert!(owned.priorities.iter().all(|e| matches!(e, Cow::Owned(_))));
+
+    let mut deque = std::collections::VecDeque::from([String::from("x")]);
+    deque.push_front(String::from("w"));
+    assert_eq!(deque.into_owned(), ["w", "x"]);
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Boxed<'a> {
+    name: Box<str>,
+    title: Box<Cow<'a, str>>,
+    tags: Box<[Cow<'a, str>]>,
+    count: Box<u32>,
+}
+
+#[test]
+fn test_boxes_convert_their_contents() {
+    let text = String::from("a b");
+    let boxed = Boxed {
+        name: "name".into(),
+        title: Box::new(Cow::Borrowed(&text[..1])),
+        tags: vec![Cow::Borrowed(&text[2..])].into_boxed_slice(),
+        count: Box::new(3),
+    };
+    let count = &*boxed.count as *const u32;
+    let owned: Boxed<'static> = boxed.into_owned();
+    drop(text);
+    assert_eq!(&*owned.name, "name");
+    assert!(matches!(&*owned.title, Cow::Owned(t) if t == "a"));
+    assert!(matches!(&owned.tags[..], [Cow::Owned(t)] if t == "b"));
+    assert_eq!(&*owned.count as *const u32, count);
+
+    let names: Box<[String]> = vec![String::from("x")].into_boxed_slice();
+    let ptr = names.as_ptr();
+    let names = names.into_owned();
+    assert_eq!(names.as_ptr(), ptr);
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Shared<'a> {
+    title: std::sync::Arc<Cow<'a, str>>,
+    tags: std::rc::Rc<Vec<Cow<'a, str>>>,
+    name: std::sync::Arc<str>,
+    config: std::sync::Arc<Vec<u8>>,
+}
+
+#[test]
+fn test_shared_pointers_unwrap_or_clone() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let text = String::from("a b");
+    let tags = Rc::new(vec![Cow::Borrowed(&text[2..])]);
+    let config = Arc::new(vec![1, 2]);
+    let shared = Shared {
+        title: Arc::new(Cow::Borrowed(&text[..1])),
+        tags: Rc::clone(&tags),
+        name: "name".into(),
+        config: Arc::clone(&config),
+    };
+    let owned: Shared<'static> = shared.into_owned();
+    assert!(matches!(&*owned.title, Cow::Owned(t) if t == "a"));
+    assert!(matches!(&owned.tags[..], [Cow::Owned(t)] if t == "b"));
+    assert!(matches!(&tags[..], [Cow::Borrowed(_)]));
+    drop(tags);
+    drop(text);
+    assert_eq!(&*owned.name, "name");
+    assert!(Arc::ptr_eq(&owned.config, &config));
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Pairs<'a> {
+    pair: (Cow<'a, str>, u32),
+    entries: Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+}
+
+#[test]
+fn test_tuples_convert_each_element() {
+    let text = String::from("a b");
+    let pairs = Pairs {
+        pair: (Cow::Borrowed(&text[..1]), 1),
+        entries: vec![(Cow::Borrowed(&text[2..]), Some(Cow::Borrowed(&text[..1])))],
+    };
+    let owned: Pairs<'static> = pairs.into_owned();
+    drop(text);
+    assert!(matches!(&owned.pair, (Cow::Owned(a), 1) if a == "a"));
+    assert!(matches!(
+        &owned.entries[..],
+        [(Cow::Owned(b), Some(Cow::Owned(a)))] if b == "b" && a == "a"
+    ));
+
+    let wide = (
+        1u8,
+        2u16,
+        3u32,
+        4u64,
+        5i8,
+        6i16,
+        7i32,
+        8i64,
+        'a',
+        true,
+        1.0f32,
+        2.0f64,
+        9usize,
+        10isize,
+        11u128,
+        String::from("x"),
+    );
+    assert_eq!(wide.into_owned().15, "x");
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Record<'a, const N: usize> {
+    columns: [Cow<'a, str>; N],
+    widths: [u16; N],
+}
+
+#[test]
+fn test_arrays_convert_element_wise() {
+    let text = String::from("a b");
+    let record = Record {
+        columns: [Cow::Borrowed(&text[..1]), Cow::Borrowed(&text[2..])],
+        widths: [1, 2],
+    };
+    let owned: Record<'static, 2> = record.into_owned();
+    drop(text);
+    assert_eq!(owned.columns, ["a", "b"]);
+    assert!(owned.columns.iter().all(|c| matches!(c, Cow::Owned(_))));
+    assert_eq!(owned.widths, [1, 2]);
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Wrappers<'a> {
+    marker: std::marker::PhantomData<&'a str>,
+    unit: (),
+    counter: std::num::Wrapping<u32>,
+    id: std::num::NonZeroU64,
+    lower: std::ops::Bound<Cow<'a, str>>,
+    span: std::ops::Range<usize>,
+    keys: std::ops::RangeInclusive<Cow<'a, str>>,
+}
+
+#[test]
+fn test_std_wrappers_convert_their_contents() {
+    let text = String::from("a b");
+    let wrappers = Wrappers {
+        marker: std::marker::PhantomData,
+        unit: (),
+        counter: std::num::Wrapping(u32::MAX),
+        id: std::num::NonZeroU64::MIN,
+        lower: std::ops::Bound::Included(Cow::Borrowed(&text[..1])),
+        span: 1..2,
+        keys: Cow::Borrowed(&text[..1])..=Cow::Borrowed(&text[2..]),
+    };
+    let owned: Wrappers<'static> = wrappers.into_owned();
+    drop(text);
+    assert_eq!(owned.counter + std::num::Wrapping(1), std::num::Wrapping(0));
+    assert_eq!(owned.id.get(), 1);
+    assert!(matches!(owned.lower, std::ops::Bound::Included(Cow::Owned(l)) if l == "a"));
+    assert_eq!(owned.span, 1..2);
+    assert!(matches!(owned.keys.start(), Cow::Owned(s) if s == "a"));
+    assert!(matches!(owned.keys.end(), Cow::Owned(e) if e == "b"));
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Telemetry<'a> {
+    host: Cow<'a, str>,
+    addr: std::net::SocketAddr,
+    ip: std::net::IpAddr,
+    interval: std::time::Duration,
+    started: std::time::SystemTime,
+    log: std::path::PathBuf,
+    config: Cow<'a, std::path::Path>,
+    label: std::ffi::OsString,
+}
+
+#[test]
+fn test_std_time_net_and_path_types_are_owned() {
+    let text = String::from("localhost /etc/app.toml");
+    let telemetry = Telemetry {
+        host: Cow::Borrowed(&text[..9]),
+        addr: "127.0.0.1:80".parse().unwrap(),
+        ip: "::1".parse().unwrap(),
+        interval: std::time::Duration::from_secs(5),
+        started: std::time::SystemTime::UNIX_EPOCH,
+        log: "/var/log/app".into(),
+        config: Cow::Borrowed(std::path::Path::new(&text[10..])),
+        label: "app".into(),
+    };
+    let owned: Telemetry<'static> = telemetry.into_owned();
+    drop(text);
+    assert_eq!(owned.host, "localhost");
+    assert_eq!(owned.addr.port(), 80);
+    assert!(owned.ip.is_loopback());
+    assert_eq!(owned.interval.as_secs(), 5);
+    assert_eq!(owned.started, std::time::SystemTime::UNIX_EPOCH);
+    assert_eq!(owned.log, std::path::Path::new("/var/log/app"));
+    assert!(matches!(&owned.config, Cow::Owned(p) if p == std::path::Path::new("/etc/app.toml")));
+    assert_eq!(owned.label, "app");
+}
+
+fn detach<T: OwnablePoly>(values: Vec<T>) -> Vec<OwnedOf<T>> {
+    values.into_iter().map(IntoStatic::into_static).collect()
+}
+
+#[test]
+fn test_into_static_names_the_owned_type() {
+    let text = String::from("a b");
+    let cow: Cow<'_, str> = Cow::Borrowed(&text[..1]);
+    let owned: Cow<'static, str> = cow.into_static();
+    let detached = detach(vec![Cow::Borrowed(&text[2..])]);
+    drop(text);
+    assert!(matches!(owned, Cow::Owned(a) if a == "a"));
+    assert!(matches!(&detached[..], [Cow::Owned(b)] if b == "b"));
+}
+
+#[derive(Debug, OwnablePoly)]
+struct Outlives<'a, T: 'a> {
+    name: Cow<'a, str>,
+    extra: Option<T>,
+}
+
+#[derive(Debug, OwnablePoly)]
+struct OutlivesWhere<'a, 'b: 'a, T>
+where
+    T: 'a + 'b + Clone,
+{
+    inner: Outlives<'a, T>,
+    label: Cow<'b, str>,
+}
+
+#[test]
+fn test_outlives_bounds_on_the_types_lifetimes() {
+    let text = String::from("a b");
+    let value = OutlivesWhere {
+        inner: Outlives {
+            name: Cow::Borrowed(&text[..1]),
+            extra: Some(1u8),
+        },
+        label: Cow::Borrowed(&text[2..]),
+    };
+    let owned: OutlivesWhere<'static, 'static, u8> = value.into_owned();
+    drop(text);
+    assert!(matches!(&owned.inner.name, Cow::Owned(n) if n == "a"));
+    assert_eq!(owned.inner.extra, Some(1));
+    assert!(matches!(&owned.label, Cow::Owned(l) if l == "b"));
+}
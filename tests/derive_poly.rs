@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use serde_poly::{DeserializePoly, Poly, SerializePoly};
+use serde_poly::{
+    DeserializePoly, DeserializePolyOnly, OwnablePoly, Poly, PolyMarker, PolyPair, SerializePoly,
+    SerializePolyOnly,
+};
+use std::borrow::Cow;
 
 #[derive(Debug, Serialize, Deserialize, Poly)]
 struct Owned {
@@ -7,21 +11,178 @@ struct Owned {
 }
 
 #[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(marker_fn)]
 struct Borrowed<'a> {
     value: &'a str,
 }
 
 #[derive(Debug, Serialize, Deserialize, Poly)]
-#[poly(name = "BorrowedAlias")]
+#[poly(name = "BorrowedAlias", marker_fn)]
 struct WithCustomName<'a> {
     data: &'a str,
 }
 
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(uninhabited)]
+struct TypeOnly<'a, T> {
+    data: &'a str,
+    values: Vec<T>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(uninhabited)]
+struct TypeOnlyPlain<'a> {
+    data: &'a str,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(marker_fn)]
+struct DefaultVariance<'a, T> {
+    data: &'a str,
+    value: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(variance = "contravariant", marker_fn)]
+struct Contravariant<'a, T> {
+    data: &'a str,
+    value: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(variance = "invariant", marker_fn)]
+struct Invariant<'a, T> {
+    data: &'a str,
+    value: T,
+}
+
+#[derive(Serialize, Deserialize, Poly, OwnablePoly)]
+#[serde(bound(
+    serialize = "P::Out<'a>: Serialize",
+    deserialize = "P::Out<'a>: Deserialize<'de>"
+))]
+struct Batch<'a, P: DeserializePoly> {
+    #[serde(borrow)]
+    items: Vec<P::Out<'a>>,
+    #[serde(borrow)]
+    first: Option<<P as DeserializePoly>::Out<'a>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+struct BatchItem<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(suffix = "Marker")]
+struct WithSuffix<'a> {
+    data: &'a str,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(prefix = "Erased", suffix = "", marker_fn)]
+struct WithPrefix<'a, T> {
+    data: &'a str,
+    value: T,
+}
+
 #[derive(Debug, Serialize, Deserialize, Poly)]
 struct ZerocopyBytes<'a, const LEN: usize> {
     bytes: &'a str,
 }
 
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(marker_fn)]
+struct Block<'a, T, const N: usize = { 8 * 4 }, const CHECKED: bool = true>
+where
+    T: Copy,
+{
+    #[serde(borrow)]
+    bytes: ZerocopyBytes<'a, { 2 * 4 }>,
+    #[serde(borrow)]
+    inner: ZerocopyBytes<'a, N>,
+    items: Vec<T>,
+    width: core::marker::PhantomData<[T; N]>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+struct PhantomLifetime<'a> {
+    value: u32,
+    marker: core::marker::PhantomData<&'a ()>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(force_marker)]
+struct ForcedMarker<'a> {
+    value: u32,
+    marker: core::marker::PhantomData<&'a ()>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+struct FlattenedGeneric<'a, T> {
+    name: &'a str,
+    #[serde(flatten)]
+    extra: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+struct FlattenedBorrowed<'a, T> {
+    id: u32,
+    #[serde(flatten, borrow)]
+    inner: FlattenedGeneric<'a, T>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(lifetime = "'a")]
+struct WithConfig<'a, 'cfg> {
+    data: &'a str,
+    #[serde(skip)]
+    config: Option<&'cfg str>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[cfg_attr(feature = "serde_json", poly(test))]
+struct RoundTripBorrowed<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    values: Vec<u32>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[cfg_attr(feature = "serde_json", poly(test))]
+struct RoundTripOwned {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(derive(Debug, PartialEq, Eq, Hash))]
+struct WithDerives<'a> {
+    data: &'a str,
+}
+
+mod facade {
+    pub use serde_poly as poly;
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[poly(crate = "facade::poly")]
+#[cfg_attr(feature = "serde_json", poly(test))]
+struct ThroughFacade<'a> {
+    #[serde(borrow)]
+    data: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize, SerializePolyOnly)]
+struct WriteOnly<'a> {
+    event: &'a str,
+}
+
+#[derive(Debug, Deserialize, DeserializePolyOnly)]
+struct ReadOnly<'a> {
+    path: &'a str,
+}
+
 mod visibility_scope {
     use super::*;
 
@@ -29,6 +190,221 @@ mod visibility_scope {
     pub struct Public<'a> {
         pub data: &'a str,
     }
+
+    #[derive(Debug, Serialize, Deserialize, Poly)]
+    #[poly(vis = "pub(crate)")]
+    pub struct CrateMarker<'a> {
+        pub data: &'a str,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(module = "poly_markers", marker_fn)]
+pub struct InModule<'a> {
+    pub data: &'a str,
+}
+
+#[serde_poly::serde_poly]
+#[derive(Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "serde_json", poly(test))]
+pub struct Combined<'a> {
+    #[serde(borrow)]
+    pub display_name: Cow<'a, str>,
+}
+
+impl Default for Combined<'_> {
+    fn default() -> Self {
+        Combined {
+            display_name: Cow::Borrowed("combined"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(ownable)]
+pub struct WithOwnable<'a> {
+    #[serde(borrow)]
+    pub data: Cow<'a, str>,
+}
+
+/// Borrows a session it never serializes, so the lifetime isn't tied to the deserializer.
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(phantom_lifetime)]
+pub struct Token<'a> {
+    pub id: u64,
+    #[serde(skip)]
+    pub session: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent)]
+struct Transparent<'a>(#[serde(borrow)] Borrowed<'a>);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent)]
+pub struct TransparentOwned(pub u32);
+
+pub trait Label {
+    fn label(&self) -> String;
+}
+
+impl Label for u32 {
+    fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+pub struct WhereClause<'a, T>
+where
+    T: Label + 'a,
+{
+    pub name: &'a str,
+    pub value: T,
+}
+
+pub trait Borrows<'a> {}
+
+impl Borrows<'_> for u32 {}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+pub struct BorrowedBound<'a, T: Borrows<'a> + 'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    pub value: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+pub struct DefaultParams<'a, T = u8, const N: usize = 4> {
+    pub name: &'a str,
+    pub items: Vec<T>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+pub struct BorrowedDefault<'a, T = Cow<'a, str>> {
+    pub name: &'a str,
+    pub items: Vec<T>,
+}
+
+pub trait Schema {
+    type Id: Serialize + serde::de::DeserializeOwned;
+}
+
+/// Not serializable itself, only its `Schema::Id` is.
+pub struct NumericIds;
+
+impl Schema for NumericIds {
+    type Id = u32;
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(bound = "")]
+#[poly(no_bound)]
+pub struct Record<'a, S: Schema> {
+    pub name: &'a str,
+    pub id: S::Id,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(bound(
+    serialize = "S::Id: Serialize",
+    deserialize = "S::Id: Deserialize<'de>"
+))]
+#[poly(bound(
+    serialize = "S::Id: Serialize",
+    deserialize = "S::Id: for<'de> Deserialize<'de>"
+))]
+pub struct ExplicitRecord<'a, S: Schema> {
+    pub name: &'a str,
+    pub id: S::Id,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(bound(
+    serialize = "S::Id: Serialize",
+    deserialize = "S::Id: Deserialize<'de>"
+))]
+pub struct SerdeBoundRecord<'a, S: Schema> {
+    pub name: &'a str,
+    pub id: S::Id,
+    #[serde(bound = "")]
+    pub previous: Option<S::Id>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(allow_unborrowed)]
+pub struct Unborrowed<'a> {
+    pub name: &'a str,
+    pub note: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+pub struct Pinned<'a: 'static> {
+    pub name: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+pub struct PinnedMixed<'a, 'b>
+where
+    'b: 'static,
+{
+    pub data: &'a str,
+    pub name: Cow<'b, str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+pub enum Envelope<'a, T, L> {
+    Ping,
+    Count(u32),
+    Text(&'a str),
+    #[serde(borrow)]
+    Name(Cow<'a, str>),
+    Value {
+        value: T,
+    },
+    #[serde(skip)]
+    Local(L),
+}
+
+/// Neither serializable nor deserializable.
+#[derive(Debug)]
+pub struct LocalOnly;
+
+/// Stands in for Poly types that were defined before their types moved here.
+pub mod legacy {
+    pub struct PlainPoly;
+
+    pub struct GenericPoly<T>(pub core::marker::PhantomData<T>);
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(use_marker = "legacy::PlainPoly")]
+pub struct Migrated<'a> {
+    pub name: &'a str,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(use_marker = "legacy::GenericPoly<T>")]
+pub struct MigratedGeneric<'a, T> {
+    pub name: &'a str,
+    pub value: T,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+pub struct Hooked<'a> {
+    pub id: u32,
+    #[serde(skip)]
+    pub hook: Option<Box<dyn Fn() + 'a>>,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+pub struct Filtered<'a> {
+    pub id: u32,
+    #[serde(skip)]
+    pub filter: Option<for<'x> fn(&'x str) -> &'a str>,
 }
 
 fn assert_type_eq<A, B>()
@@ -70,12 +446,326 @@ fn custom_name_attribute_applies() {
     assert_type_eq::<CustomOut<'static>, WithCustomName<'static>>();
 }
 
+#[test]
+fn uninhabited_attribute_generates_an_empty_marker() {
+    type Marker = <TypeOnly<'static, u8> as SerializePoly>::Out;
+    assert_type_eq::<Marker, TypeOnlyPoly<u8>>();
+    let value: <Marker as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"data":"d","values":[1,2]}"#).unwrap();
+    assert_eq!((value.data, value.values), ("d", vec![1, 2]));
+
+    fn assert_marker_traits<
+        T: Clone + Copy + std::fmt::Debug + Ord + std::hash::Hash + Serialize,
+    >() {
+    }
+    assert_marker_traits::<TypeOnlyPoly<u8>>();
+    assert_marker_traits::<TypeOnlyPlainPoly>();
+    assert_type_eq::<<TypeOnlyPlainPoly as DeserializePoly>::Out<'static>, TypeOnlyPlain<'static>>(
+    );
+}
+
+#[test]
+fn variance_attribute_shapes_the_marker() {
+    fn shorten<'a>(marker: DefaultVariancePoly<&'static str>) -> DefaultVariancePoly<&'a str> {
+        marker
+    }
+    fn lengthen(marker: ContravariantPoly<&str>) -> ContravariantPoly<&'static str> {
+        marker
+    }
+    fn keep(marker: InvariantPoly<&str>) -> InvariantPoly<&str> {
+        marker
+    }
+    let _ = (
+        shorten(DefaultVariance::marker()),
+        lengthen(Contravariant::marker()),
+    );
+    let _ = keep(Invariant::<&str>::marker());
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ContravariantPoly<std::rc::Rc<u8>>>();
+    assert_send_sync::<InvariantPoly<std::rc::Rc<u8>>>();
+}
+
+#[test]
+fn fields_can_hold_another_poly_types_out() {
+    type Marker = <Batch<'static, BatchItemPoly> as SerializePoly>::Out;
+    assert_type_eq::<Marker, BatchPoly<BatchItemPoly>>();
+
+    let json = r#"{"items":[{"name":"a"}],"first":{"name":"b"}}"#;
+    let batch: <Marker as DeserializePoly>::Out<'_> = serde_json::from_str(json).unwrap();
+    assert!(matches!(batch.items[0].name, Cow::Borrowed("a")));
+    assert_eq!(serde_json::to_string(&batch).unwrap(), json);
+
+    let owned: Batch<'static, BatchItemPoly> = batch.into_owned();
+    assert!(matches!(owned.items[0].name, Cow::Owned(_)));
+    assert_eq!(owned.first.unwrap().name, "b");
+}
+
+#[test]
+fn prefix_and_suffix_attributes_name_the_poly_type() {
+    assert_type_eq::<<WithSuffix<'static> as SerializePoly>::Out, WithSuffixMarker>();
+    assert_type_eq::<<WithSuffixMarker as DeserializePoly>::Out<'static>, WithSuffix<'static>>();
+
+    type PrefixOut = <WithPrefix<'static, u32> as SerializePoly>::Out;
+    assert_type_eq::<PrefixOut, ErasedWithPrefix<u32>>();
+    let value: <ErasedWithPrefix<u32> as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"data":"d","value":1}"#).unwrap();
+    assert_eq!((value.data, value.value), ("d", 1));
+    assert_eq!(
+        format!("{:?}", WithPrefix::<u32>::marker()),
+        "ErasedWithPrefix"
+    );
+}
+
+#[test]
+fn phantom_lifetimes_use_self() {
+    type SerializeOut = <PhantomLifetime<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, PhantomLifetime<'static>>();
+
+    type DeserializeOut<'de> = <PhantomLifetime<'static> as DeserializePoly>::Out<'de>;
+    assert_type_eq::<DeserializeOut<'static>, PhantomLifetime<'static>>();
+}
+
+#[test]
+fn force_marker_generates_poly_struct() {
+    type SerializeOut = <ForcedMarker<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, ForcedMarkerPoly>();
+
+    type DeserializeOut<'de> = <ForcedMarkerPoly as DeserializePoly>::Out<'de>;
+    assert_type_eq::<DeserializeOut<'static>, ForcedMarker<'static>>();
+}
+
+#[test]
+fn flattened_generic_fields_are_bounded() {
+    type Extra = std::collections::BTreeMap<String, u32>;
+
+    fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    let json = r#"{"id":1,"name":"borrowed","count":2}"#;
+    let item = deserialize::<FlattenedBorrowedPoly<Extra>>(json);
+    assert_eq!(item.inner.name, "borrowed");
+    assert_eq!(item.inner.extra["count"], 2);
+
+    type SerializeOut = <FlattenedBorrowed<'static, Extra> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, FlattenedBorrowedPoly<Extra>>();
+}
+
+#[test]
+fn lifetime_attribute_selects_deserializer_lifetime() {
+    type SerializeOut = <WithConfig<'static, 'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, WithConfigPoly>();
+
+    type DeserializeOut<'de> = <WithConfigPoly as DeserializePoly>::Out<'de>;
+    assert_type_eq::<DeserializeOut<'_>, WithConfig<'_, 'static>>();
+
+    let json = String::from(r#"{"data":"borrowed"}"#);
+    let item: DeserializeOut<'_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(item.data, "borrowed");
+    assert_eq!(item.config, None);
+}
+
+#[test]
+fn split_derives_implement_one_half() {
+    type SerializeOut = <WriteOnly<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, WriteOnlyPoly>();
+    assert_eq!(
+        serde_json::to_string(&WriteOnly { event: "click" }).unwrap(),
+        r#"{"event":"click"}"#
+    );
+
+    type DeserializeOut<'de> = <ReadOnlyPoly as DeserializePoly>::Out<'de>;
+    let item: DeserializeOut<'_> = serde_json::from_str(r#"{"path":"/etc"}"#).unwrap();
+    assert_eq!(item.path, "/etc");
+}
+
+#[test]
+fn derive_attribute_applies_to_poly_struct() {
+    let marker = WithDerivesPoly();
+    let copied = marker;
+    assert_eq!(marker, copied);
+    assert_eq!(format!("{:?}", marker), "WithDerivesPoly");
+
+    let markers: std::collections::HashSet<_> = [marker, copied].into_iter().collect();
+    assert_eq!(markers.len(), 1);
+}
+
+#[test]
+fn poly_structs_have_common_impls() {
+    const MARKER: ZerocopyBytesPoly<8> = ZerocopyBytesPoly::new();
+    fn assert_copy<T: Clone + Copy + Default>() {}
+    assert_copy::<ZerocopyBytesPoly<8>>();
+    assert_eq!(format!("{:?}", MARKER), "ZerocopyBytesPoly");
+
+    let _ = BorrowedPoly::new();
+}
+
+#[test]
+fn poly_structs_can_key_maps() {
+    // The marker mustn't require `Hash`, `Eq` or `Ord` of its type parameters.
+    #[derive(Clone, Copy)]
+    struct Unhashable;
+    let marker = ZerocopyBytesPoly::<8>::new();
+    assert_eq!(
+        marker.cmp(&ZerocopyBytesPoly::new()),
+        std::cmp::Ordering::Equal
+    );
+
+    let mut handlers = std::collections::HashMap::new();
+    handlers.insert(BlockPoly::<Unhashable>::new(), "block");
+    assert_eq!(handlers[&Block::<Unhashable>::marker()], "block");
+
+    let mut ordered = std::collections::BTreeMap::new();
+    ordered.insert(BorrowedPoly::new(), 1);
+    ordered.insert(Borrowed::marker(), 2);
+    assert_eq!(ordered.len(), 1);
+
+    let str_marker = <&str as SerializePoly>::Out::default();
+    let strs: std::collections::HashSet<_> = [str_marker, str_marker].into_iter().collect();
+    assert_eq!(strs.len(), 1);
+}
+
+#[test]
+fn const_generic_defaults_and_expressions_are_supported() {
+    type SerializeOut = <Block<'static, u8> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, BlockPoly<u8>>();
+    assert_type_eq::<BlockPoly<u8>, BlockPoly<u8, 32, true>>();
+
+    type DeserializeOut<'de> = <BlockPoly<u8, 4, false> as DeserializePoly>::Out<'de>;
+    let json = r#"{"bytes":{"bytes":"a"},"inner":{"bytes":"b"},"items":[1,2],"width":null}"#;
+    let block: DeserializeOut<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!((block.bytes.bytes, block.inner.bytes), ("a", "b"));
+    assert_eq!(block.items, [1, 2]);
+    assert_eq!(serde_json::to_string(&block).unwrap(), json);
+}
+
+#[test]
+fn crate_attribute_overrides_paths() {
+    type SerializeOut = <ThroughFacade<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, ThroughFacadePoly>();
+
+    let owned: ThroughFacade<'static> = ThroughFacade {
+        data: Cow::Borrowed("a"),
+    }
+    .into_owned();
+    assert_eq!(owned.data, "a");
+}
+
 #[test]
 fn public_poly_struct_is_public() {
     // TODO: this isn't a fair test, but can confirm it works from project usage
     let _ = visibility_scope::PublicPoly;
 }
 
+#[test]
+fn vis_attribute_overrides_poly_struct_visibility() {
+    type SerializeOut = <visibility_scope::CrateMarker<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, visibility_scope::CrateMarkerPoly>();
+}
+
+#[test]
+fn module_attribute_moves_poly_struct() {
+    type SerializeOut = <InModule<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, poly_markers::InModulePoly>();
+
+    let item: <poly_markers::InModulePoly as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"data":"nested"}"#).unwrap();
+    assert_eq!(item.data, "nested");
+}
+
+#[test]
+fn serde_poly_attribute_derives_all() {
+    type SerializeOut = <Combined<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, CombinedPoly>();
+
+    let json = r#"{"displayName":"forwarded"}"#;
+    let item: <CombinedPoly as DeserializePoly>::Out<'_> = serde_json::from_str(json).unwrap();
+    assert!(matches!(item.display_name, Cow::Borrowed("forwarded")));
+    let owned: Combined<'static> = item.into_owned();
+    assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+}
+
+#[test]
+fn ownable_attribute_implements_ownable_poly() {
+    let item: <WithOwnablePoly as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"data":"owned"}"#).unwrap();
+    let owned: WithOwnable<'static> = item.into_owned();
+    assert!(matches!(owned.data, Cow::Owned(_)));
+}
+
+#[test]
+fn phantom_lifetime_attribute_treats_type_as_owned() {
+    type SerializeOut = <Token<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, Token<'static>>();
+
+    type DeserializeOut<'de> = <Token<'static> as DeserializePoly>::Out<'de>;
+    let token: DeserializeOut<'_> = serde_json::from_str(r#"{"id":7}"#).unwrap();
+    assert_eq!((token.id, token.session), (7, None));
+}
+
+#[test]
+fn transparent_attribute_reuses_inner_poly_type() {
+    type SerializeOut = <Transparent<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, BorrowedPoly>();
+    let json = serde_json::to_string(&Transparent(Borrowed { value: "inner" })).unwrap();
+    let inner: <SerializeOut as DeserializePoly>::Out<'_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(inner.value, "inner");
+
+    type SerializeOwnedOut = <TransparentOwned as SerializePoly>::Out;
+    assert_type_eq::<SerializeOwnedOut, u32>();
+    type DeserializeOwnedOut<'de> = <TransparentOwned as DeserializePoly>::Out<'de>;
+    assert_type_eq::<DeserializeOwnedOut<'static>, u32>();
+}
+
+#[test]
+fn where_clauses_are_propagated() {
+    type SerializeOut = <WhereClause<'static, u32> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, WhereClausePoly<u32>>();
+
+    let json = r#"{"name":"n","value":7}"#;
+    fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    let value = deserialize::<WhereClausePoly<u32>>(json);
+    assert_eq!(value.value.label(), "7");
+
+    let value = deserialize::<BorrowedBoundPoly<u32>>(json);
+    assert_eq!(value.name, "n");
+    assert!(matches!(value.name, Cow::Borrowed(_)));
+}
+
+#[test]
+fn default_type_params_are_kept() {
+    type SerializeOut = <DefaultParams<'static> as SerializePoly>::Out;
+    assert_type_eq::<SerializeOut, DefaultParamsPoly>();
+    assert_type_eq::<DefaultParamsPoly, DefaultParamsPoly<u8, 4>>();
+
+    assert_type_eq::<BorrowedDefaultPoly, BorrowedDefaultPoly<Cow<'static, str>>>();
+}
+
+#[test]
+fn bound_attributes_replace_inferred_bounds() {
+    fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    let json = r#"{"name":"n","id":3}"#;
+    let record = deserialize::<RecordPoly<NumericIds>>(json);
+    assert_eq!((record.name, record.id), ("n", 3));
+    assert_eq!(serde_json::to_string(&record).unwrap(), json);
+
+    let record = deserialize::<ExplicitRecordPoly<NumericIds>>(json);
+    assert_eq!((record.name, record.id), ("n", 3));
+    assert_eq!(serde_json::to_string(&record).unwrap(), json);
+
+    let json = r#"{"name":"n","id":3,"previous":2}"#;
+    let record = deserialize::<SerdeBoundRecordPoly<NumericIds>>(json);
+    assert_eq!((record.name, record.id, record.previous), ("n", 3, Some(2)));
+    assert_eq!(serde_json::to_string(&record).unwrap(), json);
+}
+
 #[test]
 fn const_generics_are_supported() {
     type SerializeOut = <ZerocopyBytes<'static, 8> as SerializePoly>::Out;
@@ -86,3 +776,130 @@ fn const_generics_are_supported() {
 
     let _ = ZerocopyBytesPoly::<8>(::core::marker::PhantomData);
 }
+
+#[test]
+fn poly_pair_names_the_marker_in_bounds() {
+    fn marker<T: PolyPair>(_: &T) -> T::Marker
+    where
+        T::Marker: Default,
+    {
+        Default::default()
+    }
+
+    let _: BorrowedPoly = marker(&Borrowed { value: "a" });
+    assert_type_eq::<<Block<'static, u32> as PolyPair>::Marker, BlockPoly<u32>>();
+    assert_type_eq::<<Transparent<'static> as PolyPair>::Marker, BorrowedPoly>();
+    assert_type_eq::<<Owned as PolyPair>::Marker, Owned>();
+    assert_type_eq::<<Vec<u8> as PolyPair>::Marker, Vec<u8>>();
+
+    type Out<'de> = <<Borrowed<'static> as PolyPair>::Marker as DeserializePoly>::Out<'de>;
+    assert_type_eq::<Out<'static>, Borrowed<'static>>();
+}
+
+#[test]
+fn poly_markers_name_their_type() {
+    assert_eq!(BorrowedPoly::TYPE_NAME, "Borrowed");
+    assert_eq!(BorrowedAlias::TYPE_NAME, "WithCustomName");
+    assert_eq!(BlockPoly::<u32>::TYPE_NAME, "Block");
+    assert_eq!(Owned::TYPE_NAME, "Owned");
+
+    fn type_name<T: PolyPair>() -> &'static str
+    where
+        T::Marker: PolyMarker,
+    {
+        <T::Marker as PolyMarker>::TYPE_NAME
+    }
+    assert_eq!(type_name::<Borrowed<'static>>(), "Borrowed");
+    assert_eq!(type_name::<&'static str>(), "str");
+}
+
+#[test]
+fn allow_unborrowed_attribute_permits_owned_cows() {
+    let json = r#"{"name":"n","note":"owned"}"#;
+    let value: <UnborrowedPoly as DeserializePoly>::Out<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!(value.name, "n");
+    assert!(matches!(value.note, Cow::Owned(_)));
+}
+
+#[test]
+fn static_bounded_lifetimes_are_not_bound_to_de() {
+    assert_type_eq::<<Pinned<'static> as SerializePoly>::Out, Pinned<'static>>();
+    assert_type_eq::<<Pinned<'static> as DeserializePoly>::Out<'static>, Pinned<'static>>();
+
+    assert_type_eq::<<PinnedMixed<'static, 'static> as SerializePoly>::Out, PinnedMixedPoly>();
+    type Out<'de> = <PinnedMixedPoly as DeserializePoly>::Out<'de>;
+    let json = r#"{"data":"borrowed","name":"owned"}"#;
+    let value: Out<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!((value.data, &*value.name), ("borrowed", "owned"));
+}
+
+#[test]
+fn enums_with_some_borrowing_variants_are_supported() {
+    type Marker = <Envelope<'static, u32, LocalOnly> as SerializePoly>::Out;
+    assert_type_eq::<Marker, EnvelopePoly<u32, LocalOnly>>();
+
+    fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+        serde_json::from_str(json).unwrap()
+    }
+    let text = deserialize::<Marker>(r#"{"Text":"borrowed"}"#);
+    assert!(matches!(text, Envelope::Text("borrowed")));
+    let name = deserialize::<Marker>(r#"{"Name":"borrowed"}"#);
+    assert!(matches!(name, Envelope::Name(Cow::Borrowed("borrowed"))));
+    let value = deserialize::<Marker>(r#"{"Value":{"value":3}}"#);
+    assert!(matches!(value, Envelope::Value { value: 3 }));
+    assert!(matches!(deserialize::<Marker>(r#""Ping""#), Envelope::Ping));
+}
+
+#[test]
+fn use_marker_attribute_reuses_an_existing_poly_type() {
+    assert_type_eq::<<Migrated<'static> as SerializePoly>::Out, legacy::PlainPoly>();
+    let value: <legacy::PlainPoly as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"name":"n"}"#).unwrap();
+    assert_eq!(value.name, "n");
+    assert_eq!(legacy::PlainPoly::TYPE_NAME, "Migrated");
+
+    type GenericMarker = <MigratedGeneric<'static, u32> as SerializePoly>::Out;
+    assert_type_eq::<GenericMarker, legacy::GenericPoly<u32>>();
+    let value: <GenericMarker as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"name":"n","value":3}"#).unwrap();
+    assert_eq!((value.name, value.value), ("n", 3));
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+struct OwnMarker<'a> {
+    label: &'a str,
+}
+
+impl<'a> OwnMarker<'a> {
+    fn marker(&self) -> &'a str {
+        self.label
+    }
+}
+
+#[test]
+fn marker_fn_constructs_the_poly_type() {
+    let _: BorrowedPoly = Borrowed::marker();
+    let _: BlockPoly<u32> = Block::<u32>::marker();
+    let _: poly_markers::InModulePoly = InModule::marker();
+    const _: BorrowedAlias = WithCustomName::marker();
+}
+
+#[test]
+fn marker_fn_is_opt_in() {
+    let own = OwnMarker { label: "own" };
+    assert_eq!(own.marker(), "own");
+    let _: OwnMarkerPoly = OwnMarkerPoly::new();
+}
+
+#[test]
+fn lifetimes_in_trait_objects_and_fn_pointers_are_used() {
+    assert_type_eq::<<Hooked<'static> as SerializePoly>::Out, HookedPoly>();
+    assert_type_eq::<<HookedPoly as DeserializePoly>::Out<'static>, Hooked<'static>>();
+    assert_type_eq::<<Filtered<'static> as SerializePoly>::Out, FilteredPoly>();
+    assert_type_eq::<<FilteredPoly as DeserializePoly>::Out<'static>, Filtered<'static>>();
+
+    let value: <HookedPoly as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"id":3}"#).unwrap();
+    assert_eq!(value.id, 3);
+    assert!(value.hook.is_none());
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "generational-arena")]
+
+use generational_arena::Arena;
+use serde_poly::OwnablePoly;
+
+#[test]
+fn arena_into_owned_preserves_indices_and_generations() {
+    let mut arena = Arena::new();
+    let a = arena.insert(String::from("a"));
+    let stale = arena.insert(String::from("b"));
+    arena.remove(stale);
+    let b = arena.insert(String::from("b"));
+
+    let owned: Arena<String> = arena.into_owned();
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned[a], "a");
+    assert_eq!(owned[b], "b");
+    assert!(!owned.contains(stale));
+}
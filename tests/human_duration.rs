@@ -0,0 +1,48 @@
+#![cfg(feature = "humantime")]
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePolyOwned, HumanDuration, Poly};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+struct Config {
+    timeout: HumanDuration,
+}
+
+fn deserialize_owned<T: DeserializePolyOwned>(json: &str) -> T {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn serializes_as_human_readable_string() {
+    let config = Config {
+        timeout: Duration::from_secs(330).into(),
+    };
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"timeout":"5m 30s"}"#
+    );
+}
+
+#[test]
+fn deserializes_compact_and_spaced_forms() {
+    let config: Config = deserialize_owned(r#"{"timeout":"5m30s"}"#);
+    assert_eq!(*config.timeout, Duration::from_secs(330));
+
+    let config: Config = deserialize_owned(r#"{"timeout":"1h 15min"}"#);
+    assert_eq!(*config.timeout, Duration::from_secs(4500));
+}
+
+#[test]
+fn rejects_invalid_durations() {
+    let err = serde_json::from_str::<Config>(r#"{"timeout":"soon"}"#).unwrap_err();
+    let expected = humantime::parse_duration("soon").unwrap_err().to_string();
+    assert!(err.to_string().starts_with(&expected), "{err}");
+
+    let err = serde_json::from_str::<Config>(r#"{"timeout":330}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains(r#"expected a duration such as "5m 30s""#),
+        "{err}"
+    );
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, DeserializePolyOwned, SerializePoly};
+
+mod units {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Kelvin(pub f32);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Celsius(f32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meters<T>(T);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Label<'a>(&'a str);
+
+serde_poly::impl_poly!(Celsius, units::Kelvin);
+serde_poly::impl_poly!(impl<T> Meters<T> where T: Copy);
+serde_poly::impl_poly!(LabelPoly = Label<'de>);
+
+fn assert_owned<T: DeserializePolyOwned + SerializePoly<Out = T>>() {}
+
+#[test]
+fn owned_types_use_self() {
+    assert_owned::<Celsius>();
+    assert_owned::<units::Kelvin>();
+    assert_owned::<Meters<u32>>();
+}
+
+#[test]
+fn borrowed_types_get_a_poly_type() {
+    let _: <Label<'static> as SerializePoly>::Out = LabelPoly::new();
+    let label: <LabelPoly as DeserializePoly>::Out<'_> = serde_json::from_str(r#""a""#).unwrap();
+    assert_eq!(label.0, "a");
+}
@@ -0,0 +1,32 @@
+//! Generated Poly types are documented, so they compile under `deny(missing_docs)`.
+#![deny(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, Poly, SerializePoly};
+
+/// A documented type with a generated Poly type.
+#[derive(Serialize, Deserialize, Poly)]
+pub struct Documented<'a, T, const N: usize> {
+    /// Borrowed data.
+    pub data: &'a str,
+    /// Owned values.
+    pub values: Vec<T>,
+}
+
+/// A documented type with a custom Poly type doc.
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(doc = "Names `CustomDoc` in `Json<...>` wrappers.", module = "markers")]
+pub struct CustomDoc<'a> {
+    /// Borrowed data.
+    pub data: &'a str,
+}
+
+#[test]
+fn documented_poly_types() {
+    type SerializeOut = <Documented<'static, u8, 2> as SerializePoly>::Out;
+    let _ = SerializeOut::new();
+
+    let item: <markers::CustomDocPoly as DeserializePoly>::Out<'_> =
+        serde_json::from_str(r#"{"data":"docs"}"#).unwrap();
+    assert_eq!(item.data, "docs");
+}
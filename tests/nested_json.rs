@@ -0,0 +1,99 @@
+#![cfg(feature = "serde_json")]
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, NestedJson, OwnablePoly, Poly, SerializePoly};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+struct Payload<'a> {
+    id: &'a str,
+    tags: Vec<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly, OwnablePoly)]
+struct Row<'a> {
+    name: String,
+    #[serde(borrow)]
+    #[poly(nested_json)]
+    payload: NestedJson<'a, PayloadPoly>,
+    #[serde(borrow)]
+    #[poly(nested_json)]
+    counts: NestedJson<'a, Vec<u64>>,
+}
+
+#[test]
+fn nested_json_fields_have_accessors() {
+    let mut row = Row {
+        name: "row".to_string(),
+        payload: NestedJson::new(&Payload {
+            id: "p1",
+            tags: vec![1],
+        })
+        .unwrap(),
+        counts: NestedJson::new(&vec![1, 2]).unwrap(),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"row","payload":"{\"id\":\"p1\",\"tags\":[1]}","counts":"[1,2]"}"#
+    );
+
+    row.set_counts(&vec![3]).unwrap();
+    assert_eq!(row.counts().unwrap(), [3]);
+    row.set_payload(&Payload {
+        id: "p2",
+        tags: vec![],
+    })
+    .unwrap();
+    assert_eq!(row.payload().unwrap().id, "p2");
+}
+
+#[test]
+fn nested_json_borrows_from_the_parent() {
+    // Without escapes in the nested JSON, the blob is a slice of the parent document.
+    let json = r#"{"name":"row","payload":"","counts":"[4,5]"}"#;
+    let row: <RowPoly as DeserializePoly>::Out<'_> = serde_json::from_str(json).unwrap();
+    let start = json.find("[4,5]").unwrap();
+    assert!(std::ptr::eq(row.counts.as_str(), &json[start..start + 5]));
+    assert_eq!(row.counts().unwrap(), [4, 5]);
+    assert!(row.payload().is_err());
+
+    let owned: Row<'static> = row.into_owned();
+    assert_eq!(owned.counts.as_str(), "[4,5]");
+}
+
+#[test]
+fn nested_json_is_a_poly_type() {
+    type Marker = <NestedJson<'static, Vec<u64>> as SerializePoly>::Out;
+    let blob: <Marker as DeserializePoly>::Out<'_> = serde_json::from_str(r#""[1]""#).unwrap();
+    assert_eq!(blob.get().unwrap(), [1]);
+}
+
+#[test]
+fn undecodable_blobs_keep_their_json() {
+    let json = r#"{"name":"row","payload":"{\"id\":3}","counts":"[1]"}"#;
+    let row: <RowPoly as DeserializePoly>::Out<'_> = serde_json::from_str(json).unwrap();
+    let failure = row.payload.get_or_raw().unwrap_err();
+    assert_eq!(failure.raw, r#"{"id":3}"#);
+    assert!(failure.error.is_data());
+    assert_eq!(
+        failure.to_string(),
+        format!("failed to read nested JSON: {}", failure.error)
+    );
+    assert_eq!(row.counts.get_or_raw().unwrap(), [1]);
+}
+
+#[test]
+fn blobs_deserialize_in_place() {
+    let first: NestedJson<'_, Vec<u64>> = NestedJson::from_json("[1,2,3]");
+    let second: NestedJson<'_, Vec<u64>> = NestedJson::from_json("[4]");
+    let mut counts = Vec::with_capacity(8);
+    let ptr = counts.as_ptr();
+    first.get_in_place(&mut counts).unwrap();
+    assert_eq!(counts, [1, 2, 3]);
+    second.get_in_place(&mut counts).unwrap();
+    assert_eq!(counts, [4]);
+    assert_eq!(counts.as_ptr(), ptr);
+
+    let trailing: NestedJson<'_, Vec<u64>> = NestedJson::from_json("[5] [6]");
+    assert!(trailing.get_in_place(&mut counts).is_err());
+}
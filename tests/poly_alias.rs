@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, SerializePoly};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GenericEvent<'a, P> {
+    source: &'a str,
+    payload: P,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Payload {
+    id: u32,
+}
+
+type Event<'a> = GenericEvent<'a, Payload>;
+
+serde_poly::poly_alias!(
+    /// The Poly type for [`Event`].
+    EventPoly = GenericEvent<'de, Payload>
+);
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+    serde_json::from_str(json).unwrap()
+}
+
+fn serialize<T: SerializePoly>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+#[test]
+fn alias_round_trips_through_poly_type() {
+    let json = r#"{"source":"sensor","payload":{"id":1}}"#;
+    let event: Event<'_> = deserialize::<EventPoly>(json);
+    assert_eq!(event.source, "sensor");
+    assert_eq!(event.payload, Payload { id: 1 });
+    assert_eq!(serialize(&event), json);
+
+    let _: <Event<'static> as SerializePoly>::Out = EventPoly::new();
+    assert_eq!(format!("{:?}", EventPoly::default()), "EventPoly");
+}
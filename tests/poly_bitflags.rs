@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePolyOwned, OwnablePoly, Poly};
+use std::borrow::Cow;
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 2;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    struct Modes: u8 {
+        const ARCHIVED = 1;
+    }
+}
+
+serde_poly::poly_bitflags!(Permissions, Modes);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+struct Grant<'a> {
+    #[serde(borrow)]
+    user: Cow<'a, str>,
+    permissions: Permissions,
+    modes: Modes,
+}
+
+fn deserialize_owned<T: DeserializePolyOwned>(json: &str) -> T {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn bitflags_are_owned_poly_types() {
+    let permissions: Permissions = deserialize_owned(r#""READ | WRITE""#);
+    assert_eq!(permissions, Permissions::READ | Permissions::WRITE);
+}
+
+#[test]
+fn structs_with_bitflags_derive_ownable_poly() {
+    let grant = Grant {
+        user: Cow::Borrowed("alice"),
+        permissions: Permissions::READ,
+        modes: Modes::empty(),
+    };
+    let owned: Grant<'static> = grant.into_owned();
+    assert_eq!(owned.permissions, Permissions::READ);
+}
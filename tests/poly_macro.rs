@@ -0,0 +1,39 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, Poly, poly};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+struct Owned {
+    value: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+struct Borrowed<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+/// Stands in for a wrapper like `Json<'a, T>` that is parameterized by a Poly type.
+struct Blob<P: DeserializePoly>(PhantomData<P>);
+
+struct Row {
+    owned: Blob<poly!(Owned)>,
+    borrowed: Blob<poly!(Borrowed<'static, u32>)>,
+}
+
+#[test]
+fn owned_types_resolve_to_self() {
+    let owned: poly!(Owned) = Owned { value: 1 };
+    assert_eq!(owned, Owned { value: 1 });
+}
+
+#[test]
+fn borrowed_types_resolve_to_the_poly_type() {
+    let _: poly!(Borrowed<'_, u32>) = BorrowedPoly::<u32>::new();
+    let row = Row {
+        owned: Blob(PhantomData),
+        borrowed: Blob::<BorrowedPoly<u32>>(PhantomData),
+    };
+    let _ = (row.owned, row.borrowed);
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use serde_poly::DeserializePoly;
+
+/// Stands in for a crate that can't derive `Poly` for its types.
+mod vendor {
+    use std::borrow::Cow;
+
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct Record<'a, T> {
+        #[serde(borrow)]
+        pub name: Cow<'a, str>,
+        pub value: T,
+    }
+}
+
+serde_poly::poly_remote!(pub struct RecordPoly for vendor::Record<'a, T> where T: Clone);
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn remote_type_deserializes_through_poly_type() {
+    let json = r#"{"name":"borrowed","value":3}"#;
+    let record: vendor::Record<'_, u32> = deserialize::<RecordPoly<u32>>(json);
+    assert!(matches!(record.name, Cow::Borrowed("borrowed")));
+    assert_eq!(record.value, 3);
+
+    let _ = RecordPoly::<u32>::new();
+    assert_eq!(
+        serde_json::to_string(&RecordPoly::<u32>::default()).unwrap(),
+        "null"
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "rc")]
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, OwnablePoly, Poly};
+use std::borrow::Cow;
+use std::rc::{self, Rc};
+use std::sync::{self, Arc};
+
+#[derive(Debug, Serialize, Deserialize, Poly, OwnablePoly)]
+struct Cached<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    parent: rc::Weak<String>,
+    shared: sync::Weak<String>,
+}
+
+#[test]
+fn weak_pointers_serialize_their_value_and_read_back_empty() {
+    let parent = Rc::new(String::from("parent"));
+    let shared = Arc::new(String::from("shared"));
+    let cached = Cached {
+        name: Cow::Borrowed("cached"),
+        parent: Rc::downgrade(&parent),
+        shared: Arc::downgrade(&shared),
+    };
+    let json = serde_json::to_string(&cached).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"cached","parent":"parent","shared":"shared"}"#
+    );
+
+    let read: <CachedPoly as DeserializePoly>::Out<'_> = serde_json::from_str(&json).unwrap();
+    assert!(matches!(read.name, Cow::Borrowed("cached")));
+    assert!(read.parent.upgrade().is_none());
+    assert!(read.shared.upgrade().is_none());
+}
+
+#[test]
+fn weak_pointers_are_kept_by_into_owned() {
+    let parent = Rc::new(String::from("parent"));
+    let shared = Arc::new(String::from("shared"));
+    let text = String::from("cached");
+    let cached = Cached {
+        name: Cow::Borrowed(&text),
+        parent: Rc::downgrade(&parent),
+        shared: Arc::downgrade(&shared),
+    };
+    let owned: Cached<'static> = cached.into_owned();
+    drop(text);
+    assert!(matches!(owned.name, Cow::Owned(ref name) if name == "cached"));
+    assert!(owned.parent.ptr_eq(&Rc::downgrade(&parent)));
+    assert!(owned.shared.ptr_eq(&Arc::downgrade(&shared)));
+}
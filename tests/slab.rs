@@ -0,0 +1,21 @@
+#![cfg(feature = "slab")]
+
+use serde_poly::OwnablePoly;
+use slab::Slab;
+use std::borrow::Cow;
+
+#[test]
+fn slab_into_owned_preserves_keys() {
+    let mut slab = Slab::new();
+    let a = slab.insert(Cow::Borrowed("a"));
+    let b = slab.insert(Cow::Borrowed("b"));
+    let c = slab.insert(Cow::Borrowed("c"));
+    slab.remove(b);
+
+    let owned: Slab<Cow<'static, str>> = slab.into_owned();
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned[a], "a");
+    assert_eq!(owned[c], "c");
+    assert!(!owned.contains(b));
+    assert!(matches!(owned[a], Cow::Owned(_)));
+}
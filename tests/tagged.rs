@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+use std::net::Ipv4Addr;
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, OwnablePoly, Poly, SerializePoly, Tagged};
+
+mod common;
+use common::Compact;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[poly(tag = "app.Event")]
+struct Event<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[poly(tag = "app.Count")]
+struct Count(u32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Line(i64, i64),
+    Rect { w: u32, h: u32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[poly(tag = "app.Drawing")]
+struct Drawing {
+    shapes: Vec<Shape>,
+    label: Option<String>,
+    layer: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[poly(tag = "app.Host")]
+struct Host {
+    addr: Ipv4Addr,
+    port: Option<u16>,
+}
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> serde_json::Result<P::Out<'de>> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn tagged_values_round_trip_through_poly_types() {
+    let json = serde_json::to_string(&Tagged(Event {
+        name: "start".into(),
+    }))
+    .unwrap();
+    assert_eq!(json, r#"{"type":"app.Event","data":{"name":"start"}}"#);
+
+    type Marker = <Tagged<Event<'static>> as SerializePoly>::Out;
+    let event = deserialize::<Marker>(&json).unwrap();
+    assert_eq!(event.name, "start");
+
+    let count = deserialize::<Tagged<Count>>(r#"{"type":"app.Count","data":3}"#).unwrap();
+    assert_eq!(count, Tagged(Count(3)));
+}
+
+#[test]
+fn mismatched_tags_are_rejected() {
+    let json = serde_json::to_string(&Tagged(Count(3))).unwrap();
+    let err = deserialize::<Tagged<EventPoly>>(&json).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("expected a `app.Event` blob, found a `app.Count` blob"),
+        "{err}"
+    );
+
+    let err = deserialize::<Tagged<EventPoly>>(r#"{"data":3,"type":"app.Count"}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("expected a `app.Event` blob, found a `app.Count` blob"),
+        "{err}"
+    );
+    let err = deserialize::<Tagged<Count>>(r#"{"data":3}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `type`"));
+    let err = deserialize::<Tagged<Count>>(r#"{"type":"app.Count"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `data`"));
+}
+
+#[test]
+fn data_before_the_tag_is_buffered() {
+    let event = Tagged(Event {
+        name: "start".into(),
+    });
+    let value = serde_json::to_value(&event).unwrap();
+    assert_eq!(
+        value.to_string(),
+        r#"{"data":{"name":"start"},"type":"app.Event"}"#
+    );
+    let read = Tagged::<Event<'_>>::deserialize(&value).unwrap();
+    assert_eq!(read, event);
+    let count: Tagged<Count> =
+        serde_json::from_value(serde_json::to_value(Tagged(Count(3))).unwrap()).unwrap();
+    assert_eq!(count, Tagged(Count(3)));
+
+    // Strings buffered from the input are still borrowed.
+    let json = r#"{"data":{"name":"borrowed"},"type":"app.Event"}"#;
+    let event = deserialize::<Tagged<EventPoly>>(json).unwrap();
+    assert!(matches!(event.name, Cow::Borrowed("borrowed")));
+    let count = deserialize::<Tagged<Count>>(r#"{"data":3,"type":"app.Count"}"#).unwrap();
+    assert_eq!(count, Tagged(Count(3)));
+}
+
+#[test]
+fn buffered_data_keeps_its_shape() {
+    let drawing = Tagged(Drawing {
+        shapes: vec![
+            Shape::Point,
+            Shape::Circle(1.5),
+            Shape::Line(-1, 2),
+            Shape::Rect { w: 3, h: 4 },
+        ],
+        label: None,
+        layer: Some(2),
+    });
+    let value = serde_json::to_value(&drawing).unwrap();
+    let read: Tagged<Drawing> = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(read, drawing);
+    let read: Tagged<Drawing> = serde_json::from_str(&value.to_string()).unwrap();
+    assert_eq!(read, drawing);
+}
+
+#[test]
+fn buffered_data_keeps_the_format_compact() {
+    // Compact formats write an address as its four bytes rather than as a string.
+    let json = r#"{"data":{"addr":[127,0,0,1],"port":8080},"type":"app.Host"}"#;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let host = Tagged::<Host>::deserialize(Compact(&mut deserializer)).unwrap();
+    let expected = Host {
+        addr: Ipv4Addr::LOCALHOST,
+        port: Some(8080),
+    };
+    assert_eq!(host, Tagged(expected));
+}
+
+#[test]
+fn tagged_values_are_ownable() {
+    let json = r#"{"type":"app.Event","data":{"name":"borrowed"}}"#;
+    let event: Tagged<Event<'static>> =
+        deserialize::<Tagged<EventPoly>>(json).unwrap().into_owned();
+    assert_eq!(event.name, "borrowed");
+}
@@ -0,0 +1,90 @@
+use serde_poly::ToStatic;
+use std::borrow::Cow;
+use std::path::Path;
+
+#[derive(ToStatic, Debug, PartialEq)]
+#[to_static(derive(Debug, Clone, PartialEq))]
+pub struct Request<'a> {
+    method: &'a str,
+    body: &'a [u8],
+    host: Option<&'a str>,
+    headers: Vec<&'a str>,
+    query: Cow<'a, str>,
+    path: Option<&'a Path>,
+    port: u16,
+}
+
+#[derive(ToStatic)]
+#[to_static(name = "OwnedPair")]
+struct Pair<'a, T: Clone>(&'a str, &'a [T]);
+
+#[test]
+fn to_static_owns_reference_fields() {
+    let text = String::from("GET example.com a b q");
+    let bytes = vec![1, 2];
+    let request = Request {
+        method: &text[..3],
+        body: &bytes,
+        host: Some(&text[4..15]),
+        headers: vec![&text[16..17], &text[18..19]],
+        query: Cow::Borrowed(&text[20..]),
+        path: None,
+        port: 80,
+    };
+    let owned = RequestOwned::from(request);
+    drop(text);
+    drop(bytes);
+
+    let expected = RequestOwned {
+        method: String::from("GET"),
+        body: vec![1, 2],
+        host: Some(String::from("example.com")),
+        headers: vec![String::from("a"), String::from("b")],
+        query: Cow::Owned(String::from("q")),
+        path: None,
+        port: 80,
+    };
+    assert_eq!(owned, expected);
+    assert_eq!(owned.as_borrowed().method, "GET");
+    assert_eq!(owned.as_borrowed().headers, ["a", "b"]);
+    assert_eq!(owned.as_borrowed().host, Some("example.com"));
+
+    let numbers = [1, 2, 3];
+    let pair: OwnedPair<i32> = Pair("pair", &numbers[..]).into();
+    assert_eq!(pair.0, "pair");
+    assert_eq!(pair.1, [1, 2, 3]);
+    assert_eq!(pair.as_borrowed().1, [1, 2, 3]);
+}
+
+#[derive(ToStatic)]
+struct Tagged<'a, T: 'a + Clone> {
+    name: &'a str,
+    tag: T,
+}
+
+#[derive(ToStatic)]
+struct Labelled<'a, T>
+where
+    T: 'a + Clone,
+{
+    name: &'a str,
+    label: T,
+}
+
+#[test]
+fn to_static_drops_bounds_on_the_types_lifetimes() {
+    let text = String::from("a b");
+    let tagged: TaggedOwned<&str> = Tagged {
+        name: "tagged",
+        tag: &text[..1],
+    }
+    .into();
+    let labelled: LabelledOwned<&str> = Labelled {
+        name: "labelled",
+        label: &text[2..],
+    }
+    .into();
+    assert_eq!(tagged.name, "tagged");
+    assert_eq!(tagged.tag, "a");
+    assert_eq!(labelled.as_borrowed().label, "b");
+}
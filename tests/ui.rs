@@ -0,0 +1,8 @@
+//! Compile-fail tests pinning the derive's diagnostics. Regenerate the `.stderr` files with
+//! `TRYBUILD=overwrite cargo test --test ui` after an intentional change.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(no_bound, bound = "T: Clone")]
+struct Wrapper<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+fn main() {}
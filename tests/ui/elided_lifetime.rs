@@ -0,0 +1,24 @@
+use serde_poly::OwnablePoly;
+use std::borrow::Cow;
+
+#[derive(OwnablePoly)]
+struct Inner<'a> {
+    name: Cow<'a, str>,
+}
+
+// A field's lifetime must be named, which rustc reports before the derive's impl is checked, so an
+// elided lifetime is never silently read as lifetime-free.
+#[derive(OwnablePoly)]
+struct Outer<'a> {
+    inner: Inner<'_>,
+    label: Cow<'a, str>,
+}
+
+#[derive(OwnablePoly)]
+struct Pair<'a, 'b> {
+    first: Inner<'a>,
+    second: Inner<'_>,
+    label: Cow<'b, str>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_poly::OwnablePoly;
+
+#[derive(OwnablePoly)]
+struct Hooked<'a> {
+    name: &'a str,
+    hook: Option<Box<dyn Fn(&str) -> bool + 'a>>,
+    filter: for<'x> fn(&'x str) -> &'a str,
+}
+
+fn main() {}
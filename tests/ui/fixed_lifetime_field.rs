@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(lifetime = "'a")]
+enum Event<'a, 'cfg> {
+    Start { name: &'a str },
+    Config(&'cfg str),
+    #[serde(skip_deserializing)]
+    Skipped(&'cfg str),
+}
+
+fn main() {}
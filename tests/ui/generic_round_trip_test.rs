@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::{OwnablePoly, Poly};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+#[poly(test)]
+struct Wrapper<'a, T> {
+    #[serde(borrow)]
+    name: std::borrow::Cow<'a, str>,
+    value: T,
+}
+
+fn main() {}
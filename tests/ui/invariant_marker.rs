@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(variance = "invariant")]
+struct Invariant<'a, T> {
+    data: &'a str,
+    value: T,
+}
+
+fn shorten<'a>(marker: InvariantPoly<&'static str>) -> InvariantPoly<&'a str> {
+    marker
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(variance = "bivariant")]
+struct Unknown<'a> {
+    data: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use serde_poly::OwnablePoly;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+trait Schema<'s> {}
+
+#[derive(OwnablePoly)]
+struct Inline<'a, 'b: 'a, S: Schema<'b> + 'a> {
+    x: Cow<'a, str>,
+    y: Cow<'b, str>,
+    s: PhantomData<S>,
+}
+
+#[derive(OwnablePoly)]
+struct Where<'a, S>
+where
+    S: Schema<'a>,
+{
+    x: Cow<'a, str>,
+    s: PhantomData<S>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_poly::MakeOwned;
+use std::borrow::Cow;
+
+#[derive(MakeOwned)]
+struct Record<'a> {
+    name: Cow<'a, str>,
+    raw: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(name = "Named", suffix = "Marker")]
+struct Both<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(suffix = "")]
+struct Unchanged<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(prefix = "1")]
+struct Invalid<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(suffix = "Marker")]
+struct Owned {
+    data: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(marker_fn)]
+struct Owned {
+    value: String,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(uninhabited, marker_fn)]
+struct Uninhabited<'a> {
+    value: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use serde::Serialize;
+use serde_poly::Poly;
+
+#[derive(Serialize, Poly)]
+struct Borrowed<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Poly)]
+struct Owned {
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+struct Pair<'a, 'b, 'c> {
+    first: &'a str,
+    second: &'b str,
+    third: &'c str,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(name = "OwnedMarker")]
+struct Owned {
+    data: String,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+use std::borrow::Cow;
+
+#[derive(Serialize, Deserialize, Poly)]
+struct NotABlob<'a> {
+    #[serde(borrow)]
+    #[poly(nested_json)]
+    payload: Cow<'a, str>,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+struct UnknownKey<'a> {
+    #[poly(nested)]
+    payload: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use serde_poly::OwnablePoly;
+use std::borrow::Cow;
+
+#[derive(OwnablePoly)]
+struct Borrowed<'a> {
+    #[ownable(clone)]
+    name: Cow<'a, str>,
+}
+
+#[derive(OwnablePoly)]
+struct Unknown<'a> {
+    #[ownable(copy)]
+    name: Cow<'a, str>,
+}
+
+#[derive(OwnablePoly)]
+struct Both<'a> {
+    name: Cow<'a, str>,
+    #[ownable(clone, skip)]
+    id: u32,
+}
+
+#[derive(OwnablePoly)]
+#[ownable(bounds = "T: Clone")]
+struct Container<'a, T> {
+    name: Cow<'a, str>,
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_poly::OwnablePoly;
+
+#[derive(OwnablePoly)]
+struct Record<'a> {
+    name: &'a str,
+    bytes: Option<&'a [u8]>,
+    fixed: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+mod vendor {
+    #[derive(serde::Deserialize)]
+    pub struct Config {
+        pub verbose: bool,
+    }
+}
+
+serde_poly::poly_remote!(struct ConfigPoly for vendor::Config);
+
+fn main() {}
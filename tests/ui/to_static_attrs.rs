@@ -0,0 +1,19 @@
+use serde_poly::ToStatic;
+
+#[derive(ToStatic)]
+enum Borrowed<'a> {
+    Name(&'a str),
+}
+
+#[derive(ToStatic)]
+struct Owned {
+    name: String,
+}
+
+#[derive(ToStatic)]
+#[to_static(rename = "Other")]
+struct Renamed<'a> {
+    name: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+struct Inner<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent, lifetime = "'a")]
+struct WithLifetime<'a>(#[serde(borrow)] Inner<'a>);
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent, bound = "T: Clone")]
+struct WithBound<T>(T);
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent, no_bound)]
+struct WithNoBound<T>(T);
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent, allow_unborrowed)]
+struct WithAllowUnborrowed<'a>(#[serde(borrow)] Inner<'a>);
+
+#[derive(Serialize, Deserialize, Poly)]
+#[serde(transparent)]
+#[poly(transparent, use_marker = "InnerPoly")]
+struct WithUseMarker<'a>(#[serde(borrow)] Inner<'a>);
+
+fn main() {}
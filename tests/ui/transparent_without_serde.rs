@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+struct Inner<'a> {
+    data: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(transparent)]
+struct Wrapper<'a>(#[serde(borrow)] Inner<'a>);
+
+fn main() {}
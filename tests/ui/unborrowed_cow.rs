@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+struct Borrowed<'a> {
+    data: &'a str,
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    bytes: Cow<'a, [u8]>,
+}
+
+#[derive(Serialize, Deserialize, Poly)]
+enum Message<'a> {
+    #[serde(borrow)]
+    Text(Cow<'a, str>),
+    Bytes { bytes: Cow<'a, [u8]> },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(lifetime = "'b")]
+struct Borrowed<'a> {
+    data: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_poly::Poly;
+
+#[derive(Poly)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::Poly;
+
+pub struct ExistingPoly;
+
+#[derive(Serialize, Deserialize, Poly)]
+#[poly(use_marker = "ExistingPoly", name = "OtherPoly")]
+struct Borrowed<'a> {
+    data: &'a str,
+}
+
+fn main() {}
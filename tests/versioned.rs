@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_poly::{
+    BorrowPoly, DeserializePoly, MakeOwned, Poly, PolyVersion, SerializePoly, Tagged, Upgrade,
+    Versioned,
+};
+use std::borrow::Cow;
+use std::net::Ipv4Addr;
+
+mod common;
+use common::Compact;
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(version = 1)]
+struct UserV1<'a> {
+    name: &'a str,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(version = 2, upgrade_from = "UserV1<'a>")]
+struct UserV2<'a> {
+    first: &'a str,
+    last: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Poly)]
+#[poly(version = 3, upgrade_from = "UserV2<'a>", tag = "app.User")]
+struct User<'a> {
+    first: &'a str,
+    last: Option<&'a str>,
+    admin: bool,
+}
+
+impl<'a> Upgrade<UserV1<'a>> for UserV2<'a> {
+    fn upgrade(from: UserV1<'a>) -> Self {
+        UserV2 {
+            first: from.name,
+            last: None,
+        }
+    }
+}
+
+impl<'a> Upgrade<UserV2<'a>> for User<'a> {
+    fn upgrade(from: UserV2<'a>) -> Self {
+        User {
+            first: from.first,
+            last: from.last,
+            admin: false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[poly(version = 1)]
+struct Count(u32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly)]
+#[poly(version = 1)]
+struct Host(Ipv4Addr);
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> serde_json::Result<P::Out<'de>> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn current_version_round_trips() {
+    assert_eq!(User::VERSION, 3);
+    let user = User {
+        first: "Ada",
+        last: Some("Lovelace"),
+        admin: true,
+    };
+    let json = serde_json::to_string(&Versioned(user)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":3,"data":{"first":"Ada","last":"Lovelace","admin":true}}"#
+    );
+
+    type Marker = <Versioned<User<'static>> as SerializePoly>::Out;
+    let user = deserialize::<Marker>(&json).unwrap();
+    assert_eq!(
+        (user.first, user.last, user.admin),
+        ("Ada", Some("Lovelace"), true)
+    );
+
+    let count = deserialize::<Versioned<Count>>(r#"{"version":1,"data":3}"#).unwrap();
+    assert_eq!(count, Versioned(Count(3)));
+}
+
+#[test]
+fn older_versions_are_upgraded() {
+    let user =
+        deserialize::<Versioned<UserPoly>>(r#"{"version":1,"data":{"name":"Ada"}}"#).unwrap();
+    assert_eq!((user.first, user.last, user.admin), ("Ada", None, false));
+
+    let json = r#"{"version":2,"data":{"first":"Ada","last":"Lovelace"}}"#;
+    let user = deserialize::<Versioned<UserPoly>>(json).unwrap();
+    assert_eq!(
+        (user.first, user.last, user.admin),
+        ("Ada", Some("Lovelace"), false)
+    );
+}
+
+#[test]
+fn unknown_versions_are_rejected() {
+    let err = deserialize::<Versioned<UserPoly>>(r#"{"version":4,"data":{}}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("unsupported version 4 of `User`, expected at most 3"),
+        "{err}"
+    );
+    let err = deserialize::<Versioned<UserPoly>>(r#"{"version":0,"data":{}}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("unsupported version 0 of `UserV1`, expected 1"),
+        "{err}"
+    );
+    let err = deserialize::<Versioned<Count>>(r#"{"data":3}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `version`"));
+}
+
+#[test]
+fn data_before_the_version_is_buffered() {
+    let value = serde_json::to_value(Versioned(Count(3))).unwrap();
+    assert_eq!(value.to_string(), r#"{"data":3,"version":1}"#);
+    let count: Versioned<Count> = serde_json::from_value(value).unwrap();
+    assert_eq!(count, Versioned(Count(3)));
+
+    let user = User {
+        first: "Ada",
+        last: Some("Lovelace"),
+        admin: true,
+    };
+    let value = serde_json::to_value(Versioned(user)).unwrap();
+    let user = Versioned::<User<'_>>::deserialize(&value).unwrap();
+    assert_eq!(
+        (user.first, user.last, user.admin),
+        ("Ada", Some("Lovelace"), true)
+    );
+
+    // Older versions are upgraded from the buffered data too, which still borrows the input.
+    let json = r#"{"data":{"first":"Ada","last":null},"version":2}"#;
+    let user = deserialize::<Versioned<UserPoly>>(json).unwrap();
+    assert_eq!((user.first, user.last, user.admin), ("Ada", None, false));
+}
+
+#[test]
+fn buffered_data_keeps_the_format_compact() {
+    // Compact formats write an address as its four bytes rather than as a string.
+    let json = r#"{"data":[127,0,0,1],"version":1}"#;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let host = Versioned::<Host>::deserialize(Compact(&mut deserializer)).unwrap();
+    assert_eq!(host, Versioned(Host(Ipv4Addr::LOCALHOST)));
+}
+
+#[test]
+fn versioned_values_can_be_tagged() {
+    let json = r#"{"type":"app.User","data":{"version":1,"data":{"name":"Ada"}}}"#;
+    let user = deserialize::<Tagged<Versioned<UserPoly>>>(json).unwrap();
+    assert_eq!(user.first, "Ada");
+}
+
+#[test]
+fn versioned_values_forward_make_owned_and_reborrow() {
+    let mut value = Versioned(Cow::Borrowed("Ada"));
+    value.make_owned();
+    assert!(matches!(value.0, Cow::Owned(ref name) if name == "Ada"));
+
+    let borrowed = value.reborrow();
+    assert!(matches!(borrowed.0, Cow::Borrowed("Ada")));
+}
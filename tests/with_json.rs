@@ -0,0 +1,49 @@
+#![cfg(feature = "serde_json")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Payload {
+    id: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Row {
+    name: String,
+    #[serde(with = "serde_poly::with::json")]
+    payload: Payload,
+}
+
+#[test]
+fn nested_field_is_stored_as_json_string() {
+    let row = Row {
+        name: "row".to_string(),
+        payload: Payload {
+            id: 7,
+            tags: vec!["a".to_string()],
+        },
+    };
+
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"row","payload":"{\"id\":7,\"tags\":[\"a\"]}"}"#
+    );
+
+    let back: Row = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, row);
+}
+
+#[test]
+fn invalid_nested_json_is_an_error() {
+    let err = serde_json::from_str::<Row>(r#"{"name":"row","payload":"not json"}"#);
+    assert!(err.is_err());
+}
+
+#[test]
+fn nested_json_is_read_from_owned_strings() {
+    let value = serde_json::json!({"name": "row", "payload": r#"{"id":7,"tags":[]}"#});
+    let row: Row = serde_json::from_value(value).unwrap();
+    assert_eq!(row.payload.id, 7);
+}
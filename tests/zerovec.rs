@@ -0,0 +1,53 @@
+#![cfg(feature = "zerovec")]
+
+use serde::{Deserialize, Serialize};
+use serde_poly::{DeserializePoly, OwnablePoly, Poly, SerializePoly};
+use zerovec::{VarZeroVec, ZeroVec};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Poly, OwnablePoly)]
+struct Record<'a> {
+    #[serde(borrow)]
+    numbers: ZeroVec<'a, u32>,
+    #[serde(borrow)]
+    names: VarZeroVec<'a, str>,
+}
+
+fn deserialize<'de, P: DeserializePoly>(json: &'de str) -> P::Out<'de> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn zerovec_fields_round_trip() {
+    let record = Record {
+        numbers: ZeroVec::alloc_from_slice(&[1, 2, 3]),
+        names: VarZeroVec::from(&["a", "b"]),
+    };
+    let json = serde_json::to_string(&record).unwrap();
+
+    let borrowed = deserialize::<<Record<'_> as SerializePoly>::Out>(&json);
+    assert_eq!(borrowed, record);
+
+    let owned: Record<'static> = borrowed.into_owned();
+    assert_eq!(owned, record);
+}
+
+#[test]
+fn zerovec_into_owned() {
+    let bytes = [1u8, 0, 0, 0];
+    let borrowed = ZeroVec::<u32>::parse_bytes(&bytes).unwrap();
+    assert!(!borrowed.is_owned());
+
+    let owned: ZeroVec<'static, u32> = OwnablePoly::into_owned(borrowed);
+    assert!(owned.is_owned());
+    assert_eq!(owned.get(0), Some(1));
+}
+
+#[test]
+fn zerovec_markers_are_comparable() {
+    type Numbers = <ZeroVec<'static, u32> as SerializePoly>::Out;
+    type Names = <VarZeroVec<'static, str> as SerializePoly>::Out;
+    assert_eq!(Numbers::default(), Numbers::default());
+    assert_eq!(Names::default(), Names::default());
+    let names: std::collections::BTreeSet<_> = [Names::default()].into_iter().collect();
+    assert_eq!(format!("{:?}", names), "{VarZeroVecPoly}");
+}